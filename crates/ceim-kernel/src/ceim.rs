@@ -1,15 +1,57 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{mass_load, RegulatoryLimits, SupremeLimit};
+use alloc::string::{String, ToString};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+use crate::{mass_load_with, IntegrationMethod, RegulatoryLimits, SupremeLimit};
+
+/// How `k_n` on a [`CeimNodeImpact`] was arrived at.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ImpactStatus {
+    /// `omega * m_x / limit` against a finite, positive supreme limit.
+    #[default]
+    Computed,
+    /// No usable regulatory limit (none set, or non-positive), so `k_n` is
+    /// 0.0 for lack of a yardstick, not because the node is clean. Flag for
+    /// manual review rather than ranking as benign.
+    NoLimitDefined,
+    /// The raw `k_n` was non-finite or above [`K_N_MAX`] and was clamped.
+    ClampedInfinite,
+}
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CeimNodeImpact {
     pub contaminant: String,
     pub omega: f64,
+    /// Always finite; see [`K_N_MAX`].
     pub k_n: f64,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub impact_status: ImpactStatus,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+impl CeimNodeImpact {
+    /// True when the raw `k_n` was non-finite or too large and was clamped
+    /// to [`K_N_MAX`].
+    pub fn saturated(&self) -> bool {
+        self.impact_status == ImpactStatus::ClampedInfinite
+    }
+
+    /// True when `k_n` says nothing about the node and a person should look.
+    pub fn needs_review(&self) -> bool {
+        self.impact_status == ImpactStatus::NoLimitDefined
+    }
+}
+
+/// Upper bound reported for `k_n`. A near-zero limit can overflow
+/// `omega * m_x / limit` to infinity, and NaN samples make it undefined;
+/// both are reported as this fail-safe worst case (see `saturated()`)
+/// so one bad node can't turn rankings and aggregates into `inf`/`NaN`.
+pub const K_N_MAX: f64 = 1.0e9;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct TimeSample {
     pub t_hours: f64,
     pub c_in: f64,
@@ -25,17 +67,98 @@ impl CeimKernel {
         omega: f64,
         samples: &[TimeSample],
         limits: &RegulatoryLimits,
+    ) -> CeimNodeImpact {
+        Self::compute_with(contaminant, omega, samples, limits, IntegrationMethod::Trapezoidal)
+    }
+
+    pub fn compute_with(
+        contaminant: &str,
+        omega: f64,
+        samples: &[TimeSample],
+        limits: &RegulatoryLimits,
+        method: IntegrationMethod,
     ) -> CeimNodeImpact {
         let supreme = limits.supreme();
-        let m_x = mass_load(samples);
-        let mut k_n = 0.0;
-        if supreme.value > 0.0 {
-            k_n = omega * m_x / supreme.value;
+        let m_x = mass_load_with(samples, method);
+        // `supreme()` is +inf when no limit is set at all.
+        if !(supreme.value.is_finite() && supreme.value > 0.0) {
+            return CeimNodeImpact {
+                contaminant: contaminant.to_string(),
+                omega,
+                k_n: 0.0,
+                impact_status: ImpactStatus::NoLimitDefined,
+            };
         }
+        let k_n = omega * m_x / supreme.value;
+        let (k_n, impact_status) = if !k_n.is_finite() || k_n > K_N_MAX {
+            (K_N_MAX, ImpactStatus::ClampedInfinite)
+        } else {
+            (k_n, ImpactStatus::Computed)
+        };
         CeimNodeImpact {
             contaminant: contaminant.to_string(),
             omega,
             k_n,
+            impact_status,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples() -> [TimeSample; 2] {
+        let s = |t_hours| TimeSample {
+            t_hours,
+            c_in: 10.0,
+            c_out: 0.0,
+            flow_q: 1.0,
+        };
+        [s(0.0), s(1.0)]
+    }
+
+    #[test]
+    fn tiny_limit_saturates_instead_of_overflowing() {
+        let limits = RegulatoryLimits {
+            epa: Some(1e-300),
+            eu: None,
+            who: None,
+        };
+        let impact = CeimKernel::compute("nitrate", 1e10, &samples(), &limits);
+        assert!(impact.saturated());
+        assert_eq!(impact.impact_status, ImpactStatus::ClampedInfinite);
+        assert_eq!(impact.k_n, K_N_MAX);
+
+        let limits = RegulatoryLimits {
+            epa: Some(1.0),
+            eu: None,
+            who: None,
+        };
+        let impact = CeimKernel::compute("nitrate", 1.0, &samples(), &limits);
+        assert!(!impact.saturated());
+        assert_eq!(impact.k_n, 10.0);
+        assert_eq!(impact.impact_status, ImpactStatus::Computed);
+    }
+
+    #[test]
+    fn missing_or_non_positive_limit_is_flagged_not_clean() {
+        for limits in [
+            RegulatoryLimits {
+                epa: None,
+                eu: None,
+                who: None,
+            },
+            RegulatoryLimits {
+                epa: Some(0.0),
+                eu: None,
+                who: None,
+            },
+        ] {
+            let impact = CeimKernel::compute("pfas", 1.0, &samples(), &limits);
+            assert_eq!(impact.k_n, 0.0);
+            assert_eq!(impact.impact_status, ImpactStatus::NoLimitDefined);
+            assert!(impact.needs_review());
         }
     }
 }
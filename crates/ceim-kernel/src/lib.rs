@@ -1,7 +1,27 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 mod ceim;
 mod mass_load;
+mod omega;
 mod regulatory;
+mod resample;
+// Float rounding needs `std` (no `libm` fallback).
+#[cfg(feature = "std")]
+mod round;
+mod score;
+mod taxonomy;
 
-pub use ceim::{CeimKernel, CeimNodeImpact};
-pub use mass_load::mass_load;
+pub use ceim::{CeimKernel, CeimNodeImpact, ImpactStatus, TimeSample};
+pub use mass_load::{
+    mass_load, mass_load_breakdown, mass_load_with, mass_load_with_integrand,
+    mass_load_with_integrand_and_method, removal, IntegrationMethod, IntervalMass,
+};
+pub use omega::default_omega;
 pub use regulatory::{RegulatoryLimits, SupremeLimit};
+pub use resample::resample;
+#[cfg(feature = "std")]
+pub use round::round_to;
+pub use score::OrderedScore;
+pub use taxonomy::ContaminantTaxonomy;
@@ -1,10 +1,111 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use alloc::vec::Vec;
+
 use crate::TimeSample;
 
+/// Mass load accumulated over a single sample interval.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IntervalMass {
+    pub from_t: f64,
+    pub to_t: f64,
+    pub mass: f64,
+}
+
+/// Quadrature rule used to integrate the integrand (default c_in - c_out) * Q over time.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IntegrationMethod {
+    #[default]
+    Trapezoidal,
+    /// Composite Simpson's rule over pairs of intervals (non-uniform spacing allowed);
+    /// a trailing odd interval falls back to the trapezoid.
+    Simpson,
+}
+
+/// Contaminant-removal integrand used by `mass_load`: `c_in - c_out`.
+pub fn removal(s: &TimeSample) -> f64 {
+    s.c_in - s.c_out
+}
+
 pub fn mass_load(samples: &[TimeSample]) -> f64 {
-    if samples.is_empty() {
-        return 0.0;
+    mass_load_with_integrand(samples, removal)
+}
+
+/// Trapezoidal mass load of an arbitrary concentration integrand times flow,
+/// e.g. `|s| s.c_in` for gross load or `|s| s.c_out` for net downstream.
+/// Windowing matches `mass_load`: intervals with non-positive dt are skipped.
+pub fn mass_load_with_integrand(samples: &[TimeSample], f: impl Fn(&TimeSample) -> f64) -> f64 {
+    breakdown_with(samples, &f).iter().map(|i| i.mass).sum()
+}
+
+/// `mass_load` with an explicit integration method.
+/// Runs of samples are split wherever dt is non-positive, as in `mass_load_breakdown`.
+pub fn mass_load_with(samples: &[TimeSample], method: IntegrationMethod) -> f64 {
+    integrate(samples, method, &removal)
+}
+
+/// `mass_load_with_integrand` with an explicit integration method.
+pub fn mass_load_with_integrand_and_method(
+    samples: &[TimeSample],
+    method: IntegrationMethod,
+    f: impl Fn(&TimeSample) -> f64,
+) -> f64 {
+    integrate(samples, method, &f)
+}
+
+fn integrate(
+    samples: &[TimeSample],
+    method: IntegrationMethod,
+    f: &dyn Fn(&TimeSample) -> f64,
+) -> f64 {
+    match method {
+        IntegrationMethod::Trapezoidal => breakdown_with(samples, f).iter().map(|i| i.mass).sum(),
+        IntegrationMethod::Simpson => {
+            let mut total = 0.0;
+            let mut run_start = 0;
+            for i in 1..=samples.len() {
+                let run_ends = i == samples.len() || samples[i].t_hours <= samples[i - 1].t_hours;
+                if run_ends {
+                    total += simpson_run(&samples[run_start..i], f);
+                    run_start = i;
+                }
+            }
+            total
+        }
     }
+}
+
+fn simpson_run(run: &[TimeSample], integrand: &dyn Fn(&TimeSample) -> f64) -> f64 {
+    let f = |s: &TimeSample| integrand(s) * s.flow_q;
     let mut total = 0.0;
+    let mut i = 0;
+    while i + 2 < run.len() {
+        let (a, b, c) = (&run[i], &run[i + 1], &run[i + 2]);
+        let h0 = b.t_hours - a.t_hours;
+        let h1 = c.t_hours - b.t_hours;
+        total += (h0 + h1) / 6.0
+            * ((2.0 - h1 / h0) * f(a)
+                + (h0 + h1) * (h0 + h1) / (h0 * h1) * f(b)
+                + (2.0 - h0 / h1) * f(c));
+        i += 2;
+    }
+    if i + 1 < run.len() {
+        total += breakdown_with(&run[i..], integrand).iter().map(|m| m.mass).sum::<f64>();
+    }
+    total
+}
+
+/// Per-interval contributions to `mass_load`, for diagnostics.
+/// Intervals with non-positive dt are skipped, so the masses sum to the total.
+pub fn mass_load_breakdown(samples: &[TimeSample]) -> Vec<IntervalMass> {
+    breakdown_with(samples, &removal)
+}
+
+fn breakdown_with(samples: &[TimeSample], f: &dyn Fn(&TimeSample) -> f64) -> Vec<IntervalMass> {
+    let mut out = Vec::new();
     for w in samples.windows(2) {
         let a = &w[0];
         let b = &w[1];
@@ -12,11 +113,78 @@ pub fn mass_load(samples: &[TimeSample]) -> f64 {
         if dt <= 0.0 {
             continue;
         }
-        let cin_minus_cout_a = a.c_in - a.c_out;
-        let cin_minus_cout_b = b.c_in - b.c_out;
         let q_avg = 0.5 * (a.flow_q + b.flow_q);
-        let integrand_avg = 0.5 * (cin_minus_cout_a + cin_minus_cout_b);
-        total += integrand_avg * q_avg * dt;
+        let integrand_avg = 0.5 * (f(a) + f(b));
+        out.push(IntervalMass {
+            from_t: a.t_hours,
+            to_t: b.t_hours,
+            mass: integrand_avg * q_avg * dt,
+        });
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn sample(t_hours: f64, c_in: f64) -> TimeSample {
+        TimeSample {
+            t_hours,
+            c_in,
+            c_out: 0.0,
+            flow_q: 1.0,
+        }
+    }
+
+    #[test]
+    fn breakdown_sums_to_total() {
+        let samples = vec![sample(0.0, 2.0), sample(1.0, 4.0), sample(1.0, 9.0), sample(3.0, 1.0)];
+        let breakdown = mass_load_breakdown(&samples);
+        assert_eq!(breakdown.len(), 2);
+        assert_eq!(breakdown[0].mass, 3.0);
+        assert_eq!(breakdown[1].mass, 10.0);
+        assert_eq!(mass_load(&samples), 13.0);
+    }
+
+    #[test]
+    fn simpson_is_exact_for_quadratics() {
+        let quad = |t: f64| sample(t, t * t);
+        let uniform = [quad(0.0), quad(1.0), quad(2.0)];
+        assert!((mass_load_with(&uniform, IntegrationMethod::Simpson) - 8.0 / 3.0).abs() < 1e-12);
+        let uneven = [quad(0.0), quad(1.0), quad(3.0)];
+        assert!((mass_load_with(&uneven, IntegrationMethod::Simpson) - 9.0).abs() < 1e-12);
+        assert_eq!(
+            mass_load_with(&uneven, IntegrationMethod::Trapezoidal),
+            mass_load(&uneven)
+        );
+    }
+
+    #[test]
+    fn breakdown_empty_and_single() {
+        assert!(mass_load_breakdown(&[]).is_empty());
+        assert!(mass_load_breakdown(&[sample(0.0, 1.0)]).is_empty());
+        assert_eq!(mass_load(&[sample(0.0, 1.0)]), 0.0);
+    }
+
+    #[test]
+    fn custom_integrands_split_gross_and_downstream() {
+        let s = |t_hours, c_in, c_out| TimeSample {
+            t_hours,
+            c_in,
+            c_out,
+            flow_q: 1.0,
+        };
+        let samples = vec![s(0.0, 4.0, 1.0), s(1.0, 4.0, 1.0), s(2.0, 4.0, 1.0)];
+        let gross = mass_load_with_integrand(&samples, |s| s.c_in);
+        let downstream = mass_load_with_integrand(&samples, |s| s.c_out);
+        assert_eq!(gross, 8.0);
+        assert_eq!(downstream, 2.0);
+        assert_eq!(mass_load(&samples), gross - downstream);
+        assert_eq!(
+            mass_load_with_integrand_and_method(&samples, IntegrationMethod::Simpson, |s| s.c_in),
+            8.0
+        );
     }
-    total
 }
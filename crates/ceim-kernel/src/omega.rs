@@ -0,0 +1,50 @@
+use crate::ContaminantTaxonomy;
+
+/// Relative hazard weights (nitrate = 1.0) applied on top of the regulatory
+/// normalization in `k_n`. The limit already scales for how little of a
+/// contaminant is tolerated; omega ranks how severe an exceedance is
+/// (acute vs. chronic, bioaccumulation, persistence).
+const DEFAULT_OMEGAS: &[(&str, f64)] = &[
+    // Methemoglobinemia risk; the reference species.
+    ("nitrate", 1.0),
+    // Same mechanism as nitrate but acute; its tighter limit covers most of the gap.
+    ("nitrite", 2.0),
+    // Aquatic toxicity; little direct human-health concern at typical levels.
+    ("ammonia", 0.8),
+    // Eutrophication driver, not directly toxic.
+    ("phosphate", 0.5),
+    // Aesthetic/secondary standard.
+    ("tds", 0.2),
+    // Carcinogen with no safe threshold.
+    ("arsenic", 5.0),
+    // Neurotoxic, cumulative, no safe threshold.
+    ("lead", 5.0),
+    // Persistent and bioaccumulative.
+    ("pfas", 8.0),
+];
+
+/// Evidence-based relative weight for `contaminant` (aliases such as "NO3"
+/// or "TDS" resolve through the default taxonomy). Unknown contaminants
+/// default to 1.0.
+pub fn default_omega(contaminant: &str) -> f64 {
+    let key = ContaminantTaxonomy::default().canonicalize(contaminant);
+    DEFAULT_OMEGAS
+        .iter()
+        .find(|(name, _)| *name == key)
+        .map(|(_, omega)| *omega)
+        .unwrap_or(1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn omegas_resolve_aliases_and_default_unknowns() {
+        assert_eq!(default_omega("nitrate"), 1.0);
+        assert_eq!(default_omega("NO3"), 1.0);
+        assert_eq!(default_omega("TDS"), 0.2);
+        assert!(default_omega("PFAS") > default_omega("nitrate"));
+        assert_eq!(default_omega("chromium-vi"), 1.0);
+    }
+}
@@ -1,6 +1,10 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+use alloc::vec::Vec;
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct RegulatoryLimits {
     pub epa: Option<f64>,
     pub eu: Option<f64>,
@@ -26,7 +30,8 @@ impl RegulatoryLimits {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SupremeLimit {
     pub value: f64,
 }
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+
+use crate::TimeSample;
+
+/// Linearly interpolate `samples` onto a fixed grid `t0, t0 + step, ...`,
+/// ending with the last sample's time, so nodes with different sampling
+/// cadences integrate over comparable nodes.
+///
+/// Opt-in: call it before `mass_load` only when comparing across nodes.
+/// Concentration and flow are interpolated separately, so the integral of
+/// their product shifts slightly from the raw-sample result.
+///
+/// Samples are expected in time order; one whose time does not advance past
+/// the previous kept sample is ignored. With fewer than two usable samples,
+/// or a non-positive or non-finite `step_hours`, the usable samples are
+/// returned unchanged.
+pub fn resample(samples: &[TimeSample], step_hours: f64) -> Vec<TimeSample> {
+    let mut kept: Vec<&TimeSample> = Vec::with_capacity(samples.len());
+    for s in samples {
+        let advances = match kept.last() {
+            Some(prev) => s.t_hours > prev.t_hours,
+            None => s.t_hours.is_finite(),
+        };
+        if advances {
+            kept.push(s);
+        }
+    }
+    if kept.len() < 2 || !(step_hours.is_finite() && step_hours > 0.0) {
+        return kept.into_iter().cloned().collect();
+    }
+
+    let t0 = kept[0].t_hours;
+    let t_end = kept[kept.len() - 1].t_hours;
+    // Skip a grid point this close to the end; the end point is added as-is.
+    let eps = step_hours * 1e-9;
+    let mut out = Vec::new();
+    let mut i = 0;
+    let mut k = 0usize;
+    loop {
+        let t = t0 + k as f64 * step_hours;
+        if t >= t_end - eps {
+            break;
+        }
+        while kept[i + 1].t_hours < t {
+            i += 1;
+        }
+        out.push(lerp(kept[i], kept[i + 1], t));
+        k += 1;
+    }
+    out.push(kept[kept.len() - 1].clone());
+    out
+}
+
+fn lerp(a: &TimeSample, b: &TimeSample, t: f64) -> TimeSample {
+    let w = (t - a.t_hours) / (b.t_hours - a.t_hours);
+    let mix = |x: f64, y: f64| x + (y - x) * w;
+    TimeSample {
+        t_hours: t,
+        c_in: mix(a.c_in, b.c_in),
+        c_out: mix(a.c_out, b.c_out),
+        flow_q: mix(a.flow_q, b.flow_q),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mass_load;
+
+    fn sample(t_hours: f64, c_in: f64) -> TimeSample {
+        TimeSample {
+            t_hours,
+            c_in,
+            c_out: 0.0,
+            flow_q: 1.0,
+        }
+    }
+
+    #[test]
+    fn irregular_samples_land_on_a_fixed_grid() {
+        let samples = [sample(0.0, 0.0), sample(0.5, 1.0), sample(2.5, 5.0)];
+        let grid = resample(&samples, 1.0);
+        let times: Vec<f64> = grid.iter().map(|s| s.t_hours).collect();
+        assert_eq!(times, [0.0, 1.0, 2.0, 2.5]);
+        let c_in: Vec<f64> = grid.iter().map(|s| s.c_in).collect();
+        assert_eq!(c_in, [0.0, 2.0, 4.0, 5.0]);
+        // Linear data is reproduced exactly, so the integral is unchanged here.
+        assert!((mass_load(&grid) - mass_load(&samples)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn degenerate_inputs_pass_through() {
+        let samples = [sample(0.0, 1.0), sample(0.0, 9.0), sample(1.0, 3.0)];
+        let kept = resample(&samples, 0.0);
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[1].c_in, 3.0);
+        assert_eq!(resample(&samples[..1], 1.0).len(), 1);
+        assert!(resample(&[], 1.0).is_empty());
+        assert_eq!(resample(&samples, f64::NAN).len(), 2);
+    }
+}
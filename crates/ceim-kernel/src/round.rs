@@ -0,0 +1,44 @@
+/// Round `value` to `decimals` places, half away from zero.
+///
+/// Idempotent, so re-rounding an already-rounded shard value leaves it
+/// unchanged. Non-finite values, and values too large to scale exactly, pass
+/// through. Decimals beyond 15 are clamped.
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    let scale = 10f64.powi(decimals.min(15) as i32);
+    let scaled = value * scale;
+    if !scaled.is_finite() || scaled.abs() >= 2f64.powi(52) {
+        return value;
+    }
+    scaled.round() / scale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rounds_half_away_from_zero() {
+        assert_eq!(round_to(0.125, 2), 0.13);
+        assert_eq!(round_to(-0.125, 2), -0.13);
+        assert_eq!(round_to(2.5, 0), 3.0);
+        assert_eq!(round_to(-2.5, 0), -3.0);
+        assert_eq!(round_to(-1.234_567_89, 3), -1.235);
+        assert_eq!(round_to(-0.000_000_1, 6), 0.0);
+
+        let once = round_to(-0.123_456_789, 6);
+        assert_eq!(once, -0.123_457);
+        assert_eq!(round_to(once, 6), once);
+    }
+
+    #[test]
+    fn passes_through_values_it_cannot_scale() {
+        assert!(round_to(f64::NAN, 6).is_nan());
+        assert_eq!(round_to(f64::INFINITY, 6), f64::INFINITY);
+        assert_eq!(round_to(f64::NEG_INFINITY, 6), f64::NEG_INFINITY);
+        // 1e10 scaled by 1e6 is past 2^52, where f64 can't hold the fraction.
+        assert_eq!(round_to(1e10 + 0.123_456_7, 6), 1e10 + 0.123_456_7);
+        assert_eq!(round_to(-1e10 - 0.123_456_7, 6), -1e10 - 0.123_456_7);
+        // Decimals beyond 15 are clamped.
+        assert_eq!(round_to(0.1, 20), round_to(0.1, 15));
+    }
+}
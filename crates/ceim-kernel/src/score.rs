@@ -0,0 +1,75 @@
+use core::cmp::Ordering;
+
+/// An `f64` with a total order, for sorting scores without
+/// `partial_cmp().unwrap()`.
+///
+/// NaN sorts below every number, `-inf` included, and all NaNs are equal, so
+/// a NaN score lands last in a best-first (descending) sort. `-0.0 == 0.0`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct OrderedScore(pub f64);
+
+impl Ord for OrderedScore {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.0.is_nan(), other.0.is_nan()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            // Neither is NaN, so `partial_cmp` is always `Some`.
+            (false, false) => self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal),
+        }
+    }
+}
+
+impl PartialOrd for OrderedScore {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for OrderedScore {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedScore {}
+
+impl From<f64> for OrderedScore {
+    fn from(value: f64) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    #[test]
+    fn nan_sorts_below_negative_infinity() {
+        let mut scores = [1.0, f64::NAN, f64::INFINITY, -2.0, f64::NEG_INFINITY, 0.0];
+        scores.sort_by_key(|s| OrderedScore(*s));
+        assert!(scores[0].is_nan());
+        assert_eq!(&scores[1..], &[f64::NEG_INFINITY, -2.0, 0.0, 1.0, f64::INFINITY]);
+
+        // Best-first ranking puts NaN last instead of panicking.
+        scores.sort_by_key(|s| core::cmp::Reverse(OrderedScore(*s)));
+        assert_eq!(scores[0], f64::INFINITY);
+        assert!(scores.last().unwrap().is_nan());
+    }
+
+    #[test]
+    fn equality_is_consistent_with_ordering() {
+        assert_eq!(OrderedScore(f64::NAN), OrderedScore(-f64::NAN));
+        assert_eq!(OrderedScore(0.0), OrderedScore(-0.0));
+        assert!(OrderedScore(f64::NAN) < OrderedScore(f64::NEG_INFINITY));
+        let max = [2.0, f64::NAN, 3.0]
+            .into_iter()
+            .map(OrderedScore)
+            .max()
+            .unwrap();
+        assert_eq!(max.0, 3.0);
+        let all_nan: Vec<_> = [f64::NAN, f64::NAN].into_iter().map(OrderedScore).collect();
+        assert_eq!(all_nan.iter().max(), all_nan.first());
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Maps feed-specific contaminant names onto canonical keys so the same
+/// species is grouped and limited consistently ("NO3", "Nitrate-N" -> "nitrate").
+#[derive(Clone, Debug)]
+pub struct ContaminantTaxonomy {
+    aliases: BTreeMap<String, String>,
+}
+
+const DEFAULT_ALIASES: &[(&str, &[&str])] = &[
+    ("nitrate", &["no3", "no3-n", "nitrate-n", "nitrate-nitrogen"]),
+    ("nitrite", &["no2", "no2-n", "nitrite-n"]),
+    ("ammonia", &["nh3", "nh4", "nh3-n", "ammonium"]),
+    ("phosphate", &["po4", "orthophosphate", "po4-p"]),
+    ("tds", &["total-dissolved-solids"]),
+    ("arsenic", &["as"]),
+    ("lead", &["pb"]),
+    ("pfas", &["pfas-total", "total-pfas"]),
+];
+
+impl Default for ContaminantTaxonomy {
+    fn default() -> Self {
+        let mut taxonomy = Self::empty();
+        for (canonical, aliases) in DEFAULT_ALIASES {
+            for alias in aliases.iter() {
+                taxonomy.add_alias(alias, canonical);
+            }
+        }
+        taxonomy
+    }
+}
+
+impl ContaminantTaxonomy {
+    /// A taxonomy with no aliases; names are only normalized.
+    pub fn empty() -> Self {
+        Self {
+            aliases: BTreeMap::new(),
+        }
+    }
+
+    /// Register `alias` as another name for `canonical`.
+    pub fn add_alias(&mut self, alias: &str, canonical: &str) {
+        self.aliases.insert(normalize(alias), normalize(canonical));
+    }
+
+    /// Canonical key for `name`; unknown names come back normalized.
+    pub fn canonicalize(&self, name: &str) -> String {
+        let key = normalize(name);
+        match self.aliases.get(&key) {
+            Some(canonical) => canonical.clone(),
+            None => key,
+        }
+    }
+}
+
+/// Lowercase, trim, and join words with '-' ("Nitrate N" -> "nitrate-n").
+fn normalize(name: &str) -> String {
+    name.split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+        .filter(|part| !part.is_empty())
+        .map(|part| part.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aliases_collapse_to_canonical_key() {
+        let taxonomy = ContaminantTaxonomy::default();
+        for name in ["NO3", "nitrate", "Nitrate-N", " nitrate_n "] {
+            assert_eq!(taxonomy.canonicalize(name), "nitrate");
+        }
+        assert_eq!(taxonomy.canonicalize("Chromium VI"), "chromium-vi");
+    }
+
+    #[test]
+    fn aliases_can_be_extended() {
+        let mut taxonomy = ContaminantTaxonomy::default();
+        taxonomy.add_alias("Cr6", "chromium-vi");
+        assert_eq!(taxonomy.canonicalize("CR6"), "chromium-vi");
+    }
+}
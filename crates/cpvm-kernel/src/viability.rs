@@ -1,12 +1,50 @@
+#[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+use alloc::format;
+use alloc::string::String;
+
+/// Plausible sensor range for `temperature_c`; readings outside it are faults, not heat.
+pub const TEMPERATURE_C_BOUNDS: (f64, f64) = (-50.0, 150.0);
+
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ViabilityState {
+    /// Fraction of rated load, dimensionless in `0.0..=1.0`.
     pub load_fraction: f64,
+    /// Normalised vibration index, dimensionless and `>= 0.0`; the envelope ends at 1.0.
     pub vibration_index: f64,
+    /// Equipment temperature in degrees Celsius.
     pub temperature_c: f64,
 }
 
+impl ViabilityState {
+    /// Reject readings that cannot be physical, so a bad sensor surfaces as an
+    /// error instead of a confident "outside envelope".
+    pub fn validate(&self) -> Result<(), String> {
+        if !self.load_fraction.is_finite() || !(0.0..=1.0).contains(&self.load_fraction) {
+            return Err(format!(
+                "load_fraction {} outside 0.0..=1.0",
+                self.load_fraction
+            ));
+        }
+        if !self.vibration_index.is_finite() || self.vibration_index < 0.0 {
+            return Err(format!(
+                "vibration_index {} must be finite and non-negative",
+                self.vibration_index
+            ));
+        }
+        let (lo, hi) = TEMPERATURE_C_BOUNDS;
+        if !self.temperature_c.is_finite() || !(lo..=hi).contains(&self.temperature_c) {
+            return Err(format!(
+                "temperature_c {} outside plausible range {}..={} °C",
+                self.temperature_c, lo, hi
+            ));
+        }
+        Ok(())
+    }
+}
+
 pub struct ViabilityKernel;
 
 impl ViabilityKernel {
@@ -16,3 +54,46 @@ impl ViabilityKernel {
             && (0.0..=60.0).contains(&state.temperature_c)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(load_fraction: f64, vibration_index: f64, temperature_c: f64) -> ViabilityState {
+        ViabilityState {
+            load_fraction,
+            vibration_index,
+            temperature_c,
+        }
+    }
+
+    #[test]
+    fn validate_accepts_in_range_states() {
+        assert!(state(0.7, 0.5, 30.0).validate().is_ok());
+        assert!(state(0.0, 0.0, -10.0).validate().is_ok());
+        // Valid but outside the envelope: validation and the envelope are separate checks.
+        let hot = state(1.0, 3.0, 90.0);
+        assert!(hot.validate().is_ok());
+        assert!(!ViabilityKernel::is_within_envelope(&hot));
+    }
+
+    #[test]
+    fn validate_rejects_out_of_range_inputs() {
+        for bad in [
+            state(5.0, 0.5, 30.0),
+            state(-0.1, 0.5, 30.0),
+            state(f64::NAN, 0.5, 30.0),
+            state(0.5, -1.0, 30.0),
+            state(0.5, f64::INFINITY, 30.0),
+            state(0.5, 0.5, -273.0),
+            state(0.5, 0.5, 1000.0),
+            state(0.5, 0.5, f64::NAN),
+        ] {
+            assert!(bad.validate().is_err(), "{:?}", bad);
+        }
+        assert!(state(5.0, 0.5, 30.0)
+            .validate()
+            .unwrap_err()
+            .contains("load_fraction"));
+    }
+}
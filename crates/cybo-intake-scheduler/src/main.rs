@@ -2,6 +2,7 @@ mod series;
 mod optimizer;
 
 use anyhow::Result;
+use ceim-kernel::IntegrationMethod;
 use cpvm-kernel::ViabilityState;
 
 use optimizer::optimize;
@@ -35,7 +36,7 @@ fn main() -> Result<()> {
         temperature_c: 30.0,
     };
 
-    if let Some(plan) = optimize(&series, &viability)? {
+    if let Some(plan) = optimize(&series, &viability, IntegrationMethod::default())? {
         println!(
             "Intake {}-{}, K_n(TDS)={:.3}, K_n(nitrate)={:.3}",
             plan.start_hour, plan.end_hour, plan.k_n_tds, plan.k_n_nitrate
@@ -1,5 +1,5 @@
-use anyhow::Result;
-use ceim-kernel::{CeimKernel, RegulatoryLimits, TimeSample};
+use anyhow::{anyhow, Result};
+use ceim-kernel::{default_omega, CeimKernel, IntegrationMethod, RegulatoryLimits, TimeSample};
 use cpvm-kernel::{ViabilityKernel, ViabilityState};
 
 use crate::series::TimeSeriesPoint;
@@ -14,7 +14,11 @@ pub struct IntakePlan {
 pub fn optimize(
     series: &[TimeSeriesPoint],
     viability: &ViabilityState,
+    method: IntegrationMethod,
 ) -> Result<Option<IntakePlan>> {
+    viability
+        .validate()
+        .map_err(|e| anyhow!("invalid viability state: {}", e))?;
     if !ViabilityKernel::is_within_envelope(viability) {
         return Ok(None);
     }
@@ -57,8 +61,15 @@ pub fn optimize(
             eu: Some(1.0),
             who: Some(1.0),
         };
-        let tds_impact = CeimKernel::compute("TDS", 1.0, &samples_tds, &limits);
-        let nitrate_impact = CeimKernel::compute("nitrate", 1.0, &samples_nitrate, &limits);
+        let tds_impact =
+            CeimKernel::compute_with("TDS", default_omega("TDS"), &samples_tds, &limits, method);
+        let nitrate_impact = CeimKernel::compute_with(
+            "nitrate",
+            default_omega("nitrate"),
+            &samples_nitrate,
+            &limits,
+            method,
+        );
 
         let candidate = IntakePlan {
             start_hour: a.hour,
@@ -1,33 +1,206 @@
-use axum::{routing::get, Json, Router};
-use serde::Serialize;
+use std::sync::Arc;
+use std::time::Duration;
 
-use crate::storage::{band_for_score, load_latest_shard, EcoNode};
+use anyhow::{Context, Result};
+use axum::{
+    error_handling::HandleErrorLayer,
+    extract::{Query, State},
+    http::StatusCode,
+    routing::get,
+    BoxError, Json, Router,
+};
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use tower::{load_shed::error::Overloaded, ServiceBuilder};
+use tracing::error;
+
+use crate::storage::{
+    band_for_score, group_by_band, load_band_config, merge_shards, Aggregation, BandConfig,
+    BandGroup, EcoFamily, EcoNode, EcoimpactWeights, ShardCache, SHARD_DECIMALS,
+};
+
+const SHARD_DIR: &str = "data/ceim";
+const BAND_CONFIG_PATH: &str = "data/band_thresholds.json";
+/// How long a parsed shard is served from memory before the file is re-read.
+const SHARD_CACHE_TTL: Duration = Duration::from_secs(5);
+/// Requests allowed to wait for a rate-limit slot before new ones get 429.
+const RATE_LIMIT_QUEUE: usize = 1;
+
+#[derive(Clone)]
+struct AppState {
+    shards: Arc<ShardCache>,
+    /// Loaded once by `app`; restart the dashboard to pick up changes.
+    bands: Arc<BandConfig>,
+}
 
 #[derive(Serialize)]
 struct NodeView {
     node_id: String,
     contaminant: String,
     k_n: f64,
+    ecoimpact_score: f64,
     ecoimpact_band: f64,
 }
 
-async fn list_nodes() -> Json<Vec<NodeView>> {
-    let shard = load_latest_shard("data/ceim").ok().flatten();
+fn node_views(nodes: Vec<EcoNode>, bands: &BandConfig) -> Vec<NodeView> {
     let mut out = Vec::new();
-    if let Some(s) = shard {
-        for n in s.nodes {
-            let band = band_for_score(n.k_n);
-            out.push(NodeView {
-                node_id: n.node_id,
-                contaminant: n.contaminant,
-                k_n: n.k_n,
-                ecoimpact_band: band,
-            });
-        }
+    for n in nodes {
+        let band = band_for_score(n.k_n, &bands.thresholds_for(&n.contaminant));
+        out.push(NodeView {
+            node_id: n.node_id,
+            contaminant: n.contaminant,
+            k_n: n.k_n,
+            ecoimpact_score: n.ecoimpact_score,
+            ecoimpact_band: band,
+        });
+    }
+    out
+}
+
+async fn list_nodes(State(state): State<AppState>) -> Json<Vec<NodeView>> {
+    let shard = state.shards.latest().ok().flatten();
+    Json(node_views(
+        shard.map(|s| s.nodes).unwrap_or_default(),
+        &state.bands,
+    ))
+}
+
+/// Family-level impact from the latest shard, for group-limit views.
+async fn list_families(State(state): State<AppState>) -> Json<Vec<EcoFamily>> {
+    let shard = state.shards.latest().ok().flatten();
+    Json(shard.map(|s| s.families).unwrap_or_default())
+}
+
+/// Latest shard's nodes merged into runs of equal band, in shard order.
+async fn list_band_groups(State(state): State<AppState>) -> Json<Vec<BandGroup>> {
+    let shard = state.shards.latest().ok().flatten();
+    Json(group_by_band(
+        &shard.map(|s| s.nodes).unwrap_or_default(),
+        &state.bands,
+    ))
+}
+
+#[derive(Deserialize)]
+struct WindowParams {
+    #[serde(default = "default_window_hours")]
+    hours: u32,
+    #[serde(default)]
+    agg: Aggregation,
+}
+
+fn default_window_hours() -> u32 {
+    24
+}
+
+/// Nodes aggregated over every shard from the last `hours`, e.g.
+/// `/window?hours=24&agg=mean`. Unreadable shards are skipped; I/O errors
+/// are a 500 rather than an empty window.
+async fn window_nodes(
+    State(state): State<AppState>,
+    Query(params): Query<WindowParams>,
+) -> Result<Json<Vec<NodeView>>, StatusCode> {
+    let since = Utc::now() - chrono::Duration::hours(i64::from(params.hours));
+    let shards = state.shards.since(since).map_err(|e| {
+        error!("loading shard window: {:#}", e);
+        StatusCode::INTERNAL_SERVER_ERROR
+    })?;
+    let mut merged = merge_shards(&shards, params.agg);
+    merged.round_values(SHARD_DECIMALS);
+    Ok(Json(node_views(merged.nodes, &state.bands)))
+}
+
+/// Weights the latest shard's scores were computed with, so the UI can
+/// explain each node's score; 404 until a shard publishes them.
+async fn ecoimpact_weights(
+    State(state): State<AppState>,
+) -> Result<Json<EcoimpactWeights>, StatusCode> {
+    match state.shards.latest() {
+        Ok(shard) => shard
+            .and_then(|s| s.ecoimpact_weights)
+            .map(Json)
+            .ok_or(StatusCode::NOT_FOUND),
+        Err(_) => Err(StatusCode::INTERNAL_SERVER_ERROR),
     }
-    Json(out)
 }
 
-pub fn app() -> Router {
-    Router::new().route("/nodes", get(list_nodes))
+/// Liveness: 200 whenever the process is serving requests.
+async fn healthz() -> StatusCode {
+    StatusCode::OK
+}
+
+/// Readiness: 200 only once a shard can be loaded, so traffic waits for data.
+async fn readyz(State(state): State<AppState>) -> StatusCode {
+    match state.shards.latest() {
+        Ok(Some(_)) => StatusCode::OK,
+        _ => StatusCode::SERVICE_UNAVAILABLE,
+    }
+}
+
+async fn handle_overload(err: BoxError) -> StatusCode {
+    if err.is::<Overloaded>() {
+        StatusCode::TOO_MANY_REQUESTS
+    } else {
+        StatusCode::INTERNAL_SERVER_ERROR
+    }
+}
+
+/// Data routes share one limit of `requests_per_second`; excess requests are
+/// shed with 429 rather than queued. Fails if the band config is invalid.
+///
+/// `Router::layer` would give each route its own limiter, so the data routes
+/// are built into one service and wrapped once.
+pub fn app(requests_per_second: u64) -> Result<Router> {
+    let bands = load_band_config(BAND_CONFIG_PATH)
+        .with_context(|| format!("loading band config {}", BAND_CONFIG_PATH))?;
+    let state = AppState {
+        shards: Arc::new(ShardCache::new(SHARD_DIR, SHARD_CACHE_TTL)),
+        bands: Arc::new(bands),
+    };
+    let data = Router::new()
+        .route("/nodes", get(list_nodes))
+        .route("/families", get(list_families))
+        .route("/bands", get(list_band_groups))
+        .route("/window", get(window_nodes))
+        .route("/ecoimpact/weights", get(ecoimpact_weights))
+        .with_state(state.clone());
+    let limited_data = ServiceBuilder::new()
+        .layer(HandleErrorLayer::new(handle_overload))
+        .load_shed()
+        .buffer(RATE_LIMIT_QUEUE)
+        .rate_limit(requests_per_second, Duration::from_secs(1))
+        .service(data);
+    // Probes are mounted alongside data routes and carry no auth of their own;
+    // they are also exempt from the rate limit so orchestrators never see 429.
+    Ok(Router::new()
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .fallback_service(limited_data)
+        .with_state(state))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use tower::ServiceExt;
+
+    fn get_request(uri: &str) -> Request<Body> {
+        Request::builder().uri(uri).body(Body::empty()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn rate_limit_is_shared_across_data_routes() {
+        let app = app(1).unwrap();
+        // Issued together: the first takes the only queue slot, so the second
+        // is shed even though it targets a different route.
+        let (nodes, families) = tokio::join!(
+            app.clone().oneshot(get_request("/nodes")),
+            app.clone().oneshot(get_request("/families")),
+        );
+        assert_eq!(nodes.unwrap().status(), StatusCode::OK);
+        assert_eq!(families.unwrap().status(), StatusCode::TOO_MANY_REQUESTS);
+
+        let health = app.oneshot(get_request("/healthz")).await.unwrap();
+        assert_eq!(health.status(), StatusCode::OK);
+    }
 }
@@ -7,17 +7,25 @@ use axum::Router;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
+/// Default per-second request budget for data routes; override with
+/// `ECONET_RATE_LIMIT_RPS`.
+const DEFAULT_RATE_LIMIT_RPS: u64 = 20;
+
 #[tokio::main]
-async fn main() {
+async fn main() -> anyhow::Result<()> {
     tracing_subscriber::fmt()
         .with_env_filter(EnvFilter::from_default_env())
         .init();
 
-    let app: Router = api::app();
+    let rps = std::env::var("ECONET_RATE_LIMIT_RPS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_RATE_LIMIT_RPS);
+    let app: Router = api::app(rps)?;
     let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
-    info!("EcoNet dashboard API listening on {}", addr);
+    info!("EcoNet dashboard API listening on {} ({} req/s)", addr, rps);
     axum::Server::bind(&addr)
         .serve(app.into_make_service())
-        .await
-        .unwrap();
+        .await?;
+    Ok(())
 }
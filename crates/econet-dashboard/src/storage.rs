@@ -1,8 +1,21 @@
+use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use anyhow::Result;
+use anyhow::{anyhow, bail, Result};
+use ceim_kernel::round_to;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+/// Shard schema version this reader understands (matches phoenix-bridge).
+pub const SHARD_SCHEMA_VERSION: u32 = 2;
+/// Decimal places kept for impact values in served shards (matches phoenix-bridge).
+pub const SHARD_DECIMALS: u32 = 6;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EcoNode {
@@ -12,34 +25,568 @@ pub struct EcoNode {
     pub ecoimpact_score: f64,
 }
 
+/// Contaminant-family rollup for one node, as written by phoenix-bridge.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EcoFamily {
+    pub node_id: String,
+    pub family: String,
+    pub k_n: f64,
+    pub members: Vec<String>,
+    pub needs_review: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct EcoShard {
+    pub schema_version: u32,
     pub generated_at: String,
     pub nodes: Vec<EcoNode>,
+    /// Absent from shards written before family rollups existed.
+    #[serde(default)]
+    pub families: Vec<EcoFamily>,
+    /// Scoring context behind each node's `ecoimpact_score`; absent from
+    /// shards written before phoenix-bridge published it.
+    #[serde(default)]
+    pub ecoimpact_weights: Option<EcoimpactWeights>,
 }
 
+impl EcoShard {
+    /// Round every node's `k_n` and `ecoimpact_score` to `decimals` places,
+    /// e.g. after `merge_shards` averages them.
+    pub fn round_values(&mut self, decimals: u32) {
+        for node in &mut self.nodes {
+            node.k_n = round_to(node.k_n, decimals);
+            node.ecoimpact_score = round_to(node.ecoimpact_score, decimals);
+        }
+        for family in &mut self.families {
+            family.k_n = round_to(family.k_n, decimals);
+        }
+    }
+}
+
+/// Whether `name` is a published shard, as named by phoenix-bridge. Its
+/// in-progress `.ceim_shard_*.json.tmp` files and anything else are ignored.
+fn is_shard_file(name: &str) -> bool {
+    name.starts_with("ceim_shard_") && name.ends_with(".json")
+}
+
+/// The shard whose file name sorts last; names embed `generated_at`.
 pub fn load_latest_shard(dir: &str) -> Result<Option<EcoShard>> {
     let mut entries: Vec<_> = fs::read_dir(dir)?
         .filter_map(|e| e.ok())
+        .filter(|e| is_shard_file(&e.file_name().to_string_lossy()))
         .collect();
     entries.sort_by_key(|e| e.file_name());
     if let Some(last) = entries.last() {
         let mut path = PathBuf::from(dir);
         path.push(last.file_name());
-        let raw = fs::read_to_string(path)?;
-        let shard: EcoShard = serde_json::from_str(&raw)?;
-        Ok(Some(shard))
+        Ok(Some(read_shard(&path)?))
     } else {
         Ok(None)
     }
 }
 
-pub fn band_for_score(k_n_norm: f64) -> f64 {
-    if k_n_norm < 0.3 {
+/// A shard paired with its parsed `generated_at`.
+pub type DatedShard = (DateTime<Utc>, EcoShard);
+
+/// Holds the latest shard, and the shards behind window queries, for `ttl`
+/// so bursts of reads don't re-parse the files each time. Load errors are
+/// returned but never cached.
+pub struct ShardCache {
+    dir: String,
+    ttl: Duration,
+    entry: Mutex<Option<(Instant, Option<EcoShard>)>>,
+    window: Mutex<Option<(Instant, Vec<DatedShard>)>>,
+}
+
+impl ShardCache {
+    pub fn new(dir: impl Into<String>, ttl: Duration) -> Self {
+        Self {
+            dir: dir.into(),
+            ttl,
+            entry: Mutex::new(None),
+            window: Mutex::new(None),
+        }
+    }
+
+    pub fn latest(&self) -> Result<Option<EcoShard>> {
+        let mut entry = self.entry.lock().map_err(|_| anyhow!("shard cache poisoned"))?;
+        if let Some((loaded_at, shard)) = entry.as_ref() {
+            if loaded_at.elapsed() < self.ttl {
+                return Ok(shard.clone());
+            }
+        }
+        let shard = load_latest_shard(&self.dir)?;
+        *entry = Some((Instant::now(), shard.clone()));
+        Ok(shard)
+    }
+
+    /// Shards generated at or after `since`, oldest first (see `load_shards`).
+    pub fn since(&self, since: DateTime<Utc>) -> Result<Vec<EcoShard>> {
+        let mut window = self.window.lock().map_err(|_| anyhow!("shard cache poisoned"))?;
+        let fresh = matches!(&*window, Some((loaded_at, _)) if loaded_at.elapsed() < self.ttl);
+        if !fresh {
+            *window = Some((Instant::now(), load_shards(&self.dir)?));
+        }
+        Ok(window
+            .iter()
+            .flat_map(|(_, shards)| shards)
+            .filter(|(at, _)| *at >= since)
+            .map(|(_, shard)| shard.clone())
+            .collect())
+    }
+}
+
+/// Load every shard in `dir` with its `generated_at`, oldest first.
+///
+/// In-flight temp files and shards without a parseable timestamp are
+/// skipped. So are shards that fail to parse or migrate, with a warning,
+/// so one corrupt file doesn't hide the rest of the window. I/O errors
+/// are returned.
+pub fn load_shards(dir: &str) -> Result<Vec<DatedShard>> {
+    let mut shards = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        if !is_shard_file(&entry.file_name().to_string_lossy()) {
+            continue;
+        }
+        let path = entry.path();
+        let raw = match fs::read(&path) {
+            Ok(raw) => raw,
+            // Rotated away since the directory was listed.
+            Err(e) if e.kind() == ErrorKind::NotFound => continue,
+            Err(e) => return Err(e.into()),
+        };
+        let shard = match parse_shard(&path, &raw) {
+            Ok(shard) => shard,
+            Err(e) => {
+                warn!("skipping shard {}: {:#}", path.display(), e);
+                continue;
+            }
+        };
+        if let Ok(at) = DateTime::parse_from_rfc3339(&shard.generated_at) {
+            shards.push((at.with_timezone(&Utc), shard));
+        }
+    }
+    shards.sort_by_key(|(at, _)| *at);
+    Ok(shards)
+}
+
+fn read_shard(path: &Path) -> Result<EcoShard> {
+    parse_shard(path, &fs::read(path)?)
+}
+
+fn parse_shard(path: &Path, raw: &[u8]) -> Result<EcoShard> {
+    let value: Value = serde_json::from_slice(raw)?;
+    // Shards written before versioning carry no field; treat them as v0.
+    let version = value
+        .get("schema_version")
+        .and_then(Value::as_u64)
+        .unwrap_or(0) as u32;
+    if version > SHARD_SCHEMA_VERSION {
+        bail!(
+            "shard {} has schema_version {} but this reader supports up to {}",
+            path.display(),
+            version,
+            SHARD_SCHEMA_VERSION
+        );
+    }
+    let value = migrate_shard(value, version)?;
+    Ok(serde_json::from_value(value)?)
+}
+
+/// Upgrade a raw shard from `from_version` to `SHARD_SCHEMA_VERSION`,
+/// one step at a time. Add an arm here for each future layout change.
+pub fn migrate_shard(mut value: Value, from_version: u32) -> Result<Value> {
+    let mut version = from_version;
+    while version < SHARD_SCHEMA_VERSION {
+        let obj = match value.as_object_mut() {
+            Some(obj) => obj,
+            None => bail!("shard is not a JSON object"),
+        };
+        match version {
+            // v0 -> v1: only the version field itself was added.
+            0 => {}
+            1 => {
+                // v1 -> v2: nodes gained ecoimpact_score; unknown for old shards.
+                let nodes = obj
+                    .get_mut("nodes")
+                    .and_then(Value::as_array_mut)
+                    .ok_or_else(|| anyhow!("shard has no nodes array"))?;
+                for node in nodes.iter_mut().filter_map(Value::as_object_mut) {
+                    node.entry("ecoimpact_score").or_insert(Value::from(0.0));
+                }
+            }
+            other => bail!("no migration defined from shard schema_version {}", other),
+        }
+        version += 1;
+        obj.insert("schema_version".to_string(), Value::from(version));
+    }
+    Ok(value)
+}
+
+/// How per-node values are combined across a window of shards.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum Aggregation {
+    #[default]
+    Mean,
+    Max,
+    Latest,
+}
+
+/// Combine shards (oldest first) into one, keyed by `(node_id, contaminant)`.
+/// The result carries the newest shard's `generated_at`, family rollups and
+/// ecoimpact weights.
+pub fn merge_shards(shards: &[EcoShard], agg: Aggregation) -> EcoShard {
+    let mut order: Vec<(String, String)> = Vec::new();
+    let mut merged: HashMap<(String, String), (EcoNode, usize)> = HashMap::new();
+    for node in shards.iter().flat_map(|s| s.nodes.iter()) {
+        let key = (node.node_id.clone(), node.contaminant.clone());
+        match merged.get_mut(&key) {
+            None => {
+                order.push(key.clone());
+                merged.insert(key, (node.clone(), 1));
+            }
+            Some((acc, count)) => {
+                *count += 1;
+                match agg {
+                    // Accumulate sums here; divided by the count below.
+                    Aggregation::Mean => {
+                        acc.k_n += node.k_n;
+                        acc.ecoimpact_score += node.ecoimpact_score;
+                    }
+                    Aggregation::Max => {
+                        acc.k_n = acc.k_n.max(node.k_n);
+                        acc.ecoimpact_score = acc.ecoimpact_score.max(node.ecoimpact_score);
+                    }
+                    Aggregation::Latest => *acc = node.clone(),
+                }
+            }
+        }
+    }
+
+    let nodes = order
+        .into_iter()
+        .filter_map(|key| merged.remove(&key))
+        .map(|(mut node, count)| {
+            if agg == Aggregation::Mean {
+                node.k_n /= count as f64;
+                node.ecoimpact_score /= count as f64;
+            }
+            node
+        })
+        .collect();
+
+    EcoShard {
+        schema_version: SHARD_SCHEMA_VERSION,
+        generated_at: shards
+            .last()
+            .map(|s| s.generated_at.clone())
+            .unwrap_or_default(),
+        nodes,
+        families: shards
+            .last()
+            .map(|s| s.families.clone())
+            .unwrap_or_default(),
+        ecoimpact_weights: shards.last().and_then(|s| s.ecoimpact_weights.clone()),
+    }
+}
+
+/// Low/high cutoffs on normalized k_n separating the three display bands.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct BandThresholds {
+    pub low: f64,
+    pub high: f64,
+}
+
+impl Default for BandThresholds {
+    fn default() -> Self {
+        Self { low: 0.3, high: 0.7 }
+    }
+}
+
+/// Per-contaminant band thresholds, falling back to `default` for unlisted ones.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct BandConfig {
+    #[serde(default)]
+    pub default: BandThresholds,
+    #[serde(default)]
+    pub contaminants: HashMap<String, BandThresholds>,
+}
+
+impl BandConfig {
+    pub fn thresholds_for(&self, contaminant: &str) -> BandThresholds {
+        self.contaminants
+            .get(contaminant)
+            .copied()
+            .unwrap_or(self.default)
+    }
+}
+
+/// Load band thresholds from a JSON file; a missing file yields the defaults.
+pub fn load_band_config(path: &str) -> Result<BandConfig> {
+    if !Path::new(path).exists() {
+        return Ok(BandConfig::default());
+    }
+    let raw = fs::read_to_string(path)?;
+    let config: BandConfig = serde_json::from_str(&raw)?;
+    for (name, t) in std::iter::once(("default", &config.default))
+        .chain(config.contaminants.iter().map(|(k, v)| (k.as_str(), v)))
+    {
+        if t.low > t.high {
+            bail!("band thresholds for {} have low {} above high {}", name, t.low, t.high);
+        }
+    }
+    Ok(config)
+}
+
+/// Per-contaminant weights phoenix-bridge applied to normalized k_n to
+/// produce each node's `ecoimpact_score`, as published in the shard.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EcoimpactWeights {
+    /// k_n at which the normalized load saturates to 1.0.
+    pub k_n_reference: f64,
+    /// Weight per contaminant, keyed by lowercase name.
+    pub toxicity_weights: HashMap<String, f64>,
+    /// Weight for contaminants missing from `toxicity_weights`.
+    pub default_toxicity: f64,
+}
+
+pub fn band_for_score(k_n_norm: f64, thresholds: &BandThresholds) -> f64 {
+    if k_n_norm < thresholds.low {
         0.1
-    } else if k_n_norm < 0.7 {
+    } else if k_n_norm < thresholds.high {
         0.5
     } else {
         0.9
     }
 }
+
+/// A run of adjacent nodes sharing a display band, for map legends.
+#[derive(Debug, Serialize, Clone, PartialEq)]
+pub struct BandGroup {
+    pub band: f64,
+    pub node_ids: Vec<String>,
+    pub count: usize,
+}
+
+/// Merge adjacent nodes (in input order) that fall in the same band, using
+/// each node's per-contaminant thresholds. A node repeated back to back
+/// (one entry per contaminant) is listed once. Sort `nodes` by band first
+/// to get exactly one group per band.
+pub fn group_by_band(nodes: &[EcoNode], thresholds: &BandConfig) -> Vec<BandGroup> {
+    let mut groups: Vec<BandGroup> = Vec::new();
+    for node in nodes {
+        let band = band_for_score(node.k_n, &thresholds.thresholds_for(&node.contaminant));
+        match groups.last_mut() {
+            Some(group) if group.band == band => {
+                if group.node_ids.last() != Some(&node.node_id) {
+                    group.node_ids.push(node.node_id.clone());
+                    group.count += 1;
+                }
+            }
+            _ => groups.push(BandGroup {
+                band,
+                node_ids: vec![node.node_id.clone()],
+                count: 1,
+            }),
+        }
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fresh, empty directory under the system temp dir.
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("econet-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn shard_json(generated_at: &str, k_n: f64) -> String {
+        serde_json::json!({
+            "schema_version": SHARD_SCHEMA_VERSION,
+            "generated_at": generated_at,
+            "nodes": [
+                { "node_id": "n1", "contaminant": "lead", "k_n": k_n, "ecoimpact_score": k_n }
+            ],
+        })
+        .to_string()
+    }
+
+    #[test]
+    fn window_skips_corrupt_shards_and_reports_io_errors() {
+        let dir = temp_dir("window");
+        fs::write(
+            dir.join("ceim_shard_a.json"),
+            shard_json("2025-01-01T00:00:00Z", 0.1),
+        )
+        .unwrap();
+        fs::write(dir.join("ceim_shard_b.json"), "{ not json").unwrap();
+        fs::write(
+            dir.join("ceim_shard_c.json"),
+            shard_json("2025-01-03T00:00:00Z", 0.3),
+        )
+        .unwrap();
+        fs::write(dir.join(".ceim_shard_d.json.tmp"), "").unwrap();
+
+        let cache = ShardCache::new(dir.to_string_lossy(), Duration::from_secs(60));
+        let since = DateTime::parse_from_rfc3339("2025-01-02T00:00:00Z").unwrap();
+        let shards = cache.since(since.with_timezone(&Utc)).unwrap();
+        assert_eq!(shards.len(), 1);
+        assert_eq!(shards[0].nodes[0].k_n, 0.3);
+        assert_eq!(cache.since(DateTime::<Utc>::MIN_UTC).unwrap().len(), 2);
+
+        // Served from the cache until the TTL expires.
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(cache.since(DateTime::<Utc>::MIN_UTC).unwrap().len(), 2);
+        let uncached = ShardCache::new(dir.to_string_lossy(), Duration::ZERO);
+        assert!(uncached.since(DateTime::<Utc>::MIN_UTC).is_err());
+    }
+
+    #[test]
+    fn latest_shard_ignores_temp_and_foreign_files() {
+        let dir = temp_dir("latest");
+        let name = dir.to_string_lossy().to_string();
+        assert!(load_latest_shard(&name).unwrap().is_none());
+
+        fs::write(
+            dir.join("ceim_shard_2025-01-01T00_00_00Z.json"),
+            shard_json("2025-01-01T00:00:00Z", 0.1),
+        )
+        .unwrap();
+        fs::write(
+            dir.join("ceim_shard_2025-01-02T00_00_00Z.json"),
+            shard_json("2025-01-02T00:00:00Z", 0.2),
+        )
+        .unwrap();
+        // Both sort after the newest shard but must never be served.
+        fs::write(
+            dir.join(".ceim_shard_2025-01-03T00_00_00Z.json.tmp"),
+            "{ partial",
+        )
+        .unwrap();
+        fs::write(dir.join("zz_notes.json"), shard_json("2025-01-04T00:00:00Z", 0.4)).unwrap();
+
+        let latest = load_latest_shard(&name).unwrap().unwrap();
+        assert_eq!(latest.generated_at, "2025-01-02T00:00:00Z");
+        assert_eq!(load_shards(&name).unwrap().len(), 2);
+    }
+
+    fn node(node_id: &str, contaminant: &str, k_n: f64) -> EcoNode {
+        EcoNode {
+            node_id: node_id.to_string(),
+            contaminant: contaminant.to_string(),
+            k_n,
+            ecoimpact_score: k_n / 2.0,
+        }
+    }
+
+    fn shard(generated_at: &str, nodes: Vec<EcoNode>) -> EcoShard {
+        EcoShard {
+            schema_version: SHARD_SCHEMA_VERSION,
+            generated_at: generated_at.to_string(),
+            nodes,
+            families: Vec::new(),
+            ecoimpact_weights: None,
+        }
+    }
+
+    #[test]
+    fn merge_combines_overlapping_shards_per_node_and_contaminant() {
+        let shards = [
+            shard("t1", vec![node("n1", "lead", 0.6), node("n2", "lead", 0.4)]),
+            shard("t2", vec![node("n1", "lead", 0.2), node("n1", "arsenic", 0.1)]),
+        ];
+
+        let mean = merge_shards(&shards, Aggregation::Mean);
+        assert_eq!(mean.generated_at, "t2");
+        let keys: Vec<_> = mean
+            .nodes
+            .iter()
+            .map(|n| (n.node_id.as_str(), n.contaminant.as_str()))
+            .collect();
+        assert_eq!(keys, [("n1", "lead"), ("n2", "lead"), ("n1", "arsenic")]);
+        assert!((mean.nodes[0].k_n - 0.4).abs() < 1e-12);
+        assert!((mean.nodes[0].ecoimpact_score - 0.2).abs() < 1e-12);
+        assert_eq!(mean.nodes[1].k_n, 0.4);
+
+        assert_eq!(merge_shards(&shards, Aggregation::Max).nodes[0].k_n, 0.6);
+        assert_eq!(merge_shards(&shards, Aggregation::Latest).nodes[0].k_n, 0.2);
+    }
+
+    #[test]
+    fn merge_of_duplicate_shards_keeps_their_values() {
+        let repeated = shard("t1", vec![node("n1", "lead", 0.3)]);
+        let shards = [repeated.clone(), repeated];
+        for agg in [Aggregation::Mean, Aggregation::Max, Aggregation::Latest] {
+            let merged = merge_shards(&shards, agg);
+            assert_eq!(merged.nodes.len(), 1, "{:?}", agg);
+            assert!((merged.nodes[0].k_n - 0.3).abs() < 1e-12, "{:?}", agg);
+        }
+        assert!(merge_shards(&[], Aggregation::Mean).nodes.is_empty());
+    }
+
+    #[test]
+    fn migrate_upgrades_unversioned_shard() {
+        let v0 = serde_json::json!({
+            "generated_at": "2025-01-01T00:00:00Z",
+            "nodes": [{ "node_id": "n1", "contaminant": "lead", "k_n": 0.5 }],
+        });
+        let migrated = migrate_shard(v0, 0).unwrap();
+        assert_eq!(migrated["schema_version"], SHARD_SCHEMA_VERSION);
+        assert_eq!(migrated["nodes"][0]["ecoimpact_score"], 0.0);
+        let shard: EcoShard = serde_json::from_value(migrated).unwrap();
+        assert_eq!(shard.nodes[0].k_n, 0.5);
+        assert!(shard.families.is_empty());
+
+        // A v1 score that is already present is kept.
+        let v1 = serde_json::json!({
+            "schema_version": 1,
+            "generated_at": "2025-01-01T00:00:00Z",
+            "nodes": [{ "node_id": "n1", "contaminant": "lead", "k_n": 0.5, "ecoimpact_score": 0.25 }],
+        });
+        assert_eq!(migrate_shard(v1, 1).unwrap()["nodes"][0]["ecoimpact_score"], 0.25);
+
+        assert!(migrate_shard(serde_json::json!([]), 0).is_err());
+        assert!(migrate_shard(serde_json::json!({ "schema_version": 1 }), 1).is_err());
+        let newer = br#"{ "schema_version": 99, "generated_at": "", "nodes": [] }"#;
+        assert!(parse_shard(Path::new("newer.json"), newer).is_err());
+    }
+
+    #[test]
+    fn group_by_band_splits_at_threshold_edges() {
+        let mut config = BandConfig::default();
+        config
+            .contaminants
+            .insert("lead".to_string(), BandThresholds { low: 0.1, high: 0.2 });
+        // Contaminants without their own thresholds use the 0.3/0.7 default.
+        let nodes = [
+            node("a", "lead", 0.099),
+            node("b", "lead", 0.1),
+            node("b", "arsenic", 0.5),
+            node("c", "lead", 0.2),
+            node("d", "nitrate", 0.7),
+            node("e", "nitrate", 0.299),
+        ];
+        let group = |band: f64, ids: &[&str]| BandGroup {
+            band,
+            node_ids: ids.iter().map(|id| id.to_string()).collect(),
+            count: ids.len(),
+        };
+        assert_eq!(
+            group_by_band(&nodes, &config),
+            [
+                group(0.1, &["a"]),
+                group(0.5, &["b"]),
+                group(0.9, &["c", "d"]),
+                group(0.1, &["e"]),
+            ]
+        );
+        assert!(group_by_band(&[], &config).is_empty());
+    }
+}
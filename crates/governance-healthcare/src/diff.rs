@@ -0,0 +1,198 @@
+//! Field-level differences between two policy snapshots, for audits of
+//! "what changed since the last approved deploy".
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{HealthcareGovernancePolicy, HitlPattern};
+
+/// One field that differs between two policy snapshots.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PolicyFieldChange {
+    /// Dotted path of the field, e.g. `logging.tamper_evident_required`.
+    pub field: String,
+    /// Old value as rendered in the policy JSON.
+    pub from: String,
+    /// New value as rendered in the policy JSON.
+    pub to: String,
+    /// True if the change loosens controls: a lower risk tier, less human
+    /// oversight, shorter retention, or a flag in `CONTROL_FLAGS` turned off.
+    /// Descriptive facts about the deployment are never flagged.
+    pub is_safety_regression: bool,
+}
+
+/// Boolean fields that switch a safeguard on; turning one off is a regression.
+/// Other booleans describe the deployment (e.g. `uses_biosignals`,
+/// `consent_profile.fpic_granted`) and are reported but not flagged.
+pub const CONTROL_FLAGS: &[&str] = &[
+    "consent_profile.requires_individual_consent",
+    "logging.tamper_evident_required",
+    "logging.full_decision_trace_required",
+    "dataset_provenance.require_source_and_license",
+    "dataset_provenance.require_consent_and_jurisdiction_tags",
+    "dataset_provenance.require_biosignal_labelling",
+];
+
+/// Every field that differs between `old` and `new`, nested structs included,
+/// in JSON key order.
+pub fn diff_policies(
+    old: &HealthcareGovernancePolicy,
+    new: &HealthcareGovernancePolicy,
+) -> Vec<PolicyFieldChange> {
+    let before = serde_json::to_value(old).expect("policy serializes to JSON");
+    let after = serde_json::to_value(new).expect("policy serializes to JSON");
+    let mut changes = Vec::new();
+    walk("", &before, &after, &mut changes);
+    for change in &mut changes {
+        change.is_safety_regression = is_safety_regression(change, old, new);
+    }
+    changes
+}
+
+fn walk(path: &str, before: &Value, after: &Value, out: &mut Vec<PolicyFieldChange>) {
+    if let (Value::Object(a), Value::Object(b)) = (before, after) {
+        for key in a.keys().chain(b.keys().filter(|k| !a.contains_key(*k))) {
+            let field = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+            walk(
+                &field,
+                a.get(key).unwrap_or(&Value::Null),
+                b.get(key).unwrap_or(&Value::Null),
+                out,
+            );
+        }
+    } else if before != after {
+        out.push(PolicyFieldChange {
+            field: path.to_string(),
+            from: render(before),
+            to: render(after),
+            is_safety_regression: false,
+        });
+    }
+}
+
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn is_safety_regression(
+    change: &PolicyFieldChange,
+    old: &HealthcareGovernancePolicy,
+    new: &HealthcareGovernancePolicy,
+) -> bool {
+    match change.field.as_str() {
+        "risk_tier" => new.risk_tier < old.risk_tier,
+        "hitl_pattern" => oversight(&new.hitl_pattern) < oversight(&old.hitl_pattern),
+        "logging.min_retention" | "logging.min_retention_years" => {
+            new.logging.effective_retention() < old.logging.effective_retention()
+        }
+        field if CONTROL_FLAGS.contains(&field) => change.from == "true" && change.to == "false",
+        _ => false,
+    }
+}
+
+/// Amount of human oversight, higher is stricter.
+fn oversight(pattern: &HitlPattern) -> u8 {
+    match pattern {
+        HitlPattern::AutonomousWithinLimits => 0,
+        HitlPattern::HumanOverrideCapable => 1,
+        HitlPattern::HumanReviewRequired => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ClinicalRiskTier;
+
+    fn policy() -> HealthcareGovernancePolicy {
+        HealthcareGovernancePolicy::from_json_str(
+            r#"{
+                "model_id": "triage-1",
+                "owner": "clinic",
+                "clinical_use_case": "Triage",
+                "risk_tier": "High",
+                "hitl_pattern": "HumanReviewRequired",
+                "consent_profile": {
+                    "requires_individual_consent": true,
+                    "involves_indigenous_or_community_data": false,
+                    "fpic_granted": false
+                },
+                "logging": {
+                    "min_retention_years": 7,
+                    "tamper_evident_required": true,
+                    "full_decision_trace_required": true
+                },
+                "dataset_provenance": {
+                    "require_source_and_license": true,
+                    "require_consent_and_jurisdiction_tags": true,
+                    "require_biosignal_labelling": true
+                },
+                "uses_biosignals": false,
+                "touches_indigenous_data": false,
+                "created_at": "2025-01-01T00:00:00Z"
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_changes() {
+        assert!(diff_policies(&policy(), &policy()).is_empty());
+    }
+
+    #[test]
+    fn loosened_controls_are_flagged() {
+        let old = policy();
+        let mut new = policy();
+        new.owner = "ops".to_string();
+        new.risk_tier = ClinicalRiskTier::Medium;
+        new.hitl_pattern = HitlPattern::HumanOverrideCapable;
+        new.logging.tamper_evident_required = false;
+        new.logging.min_retention_years = 5;
+        new.population.includes_minors = true;
+
+        let changes = diff_policies(&old, &new);
+        let flagged = |field: &str| {
+            let change = changes.iter().find(|c| c.field == field).unwrap();
+            change.is_safety_regression
+        };
+        assert_eq!(changes.len(), 6, "{:?}", changes);
+        assert!(!flagged("owner"));
+        assert!(flagged("risk_tier"));
+        assert!(flagged("hitl_pattern"));
+        assert!(flagged("logging.tamper_evident_required"));
+        assert!(flagged("logging.min_retention_years"));
+        assert!(!flagged("population.includes_minors"));
+
+        let tier = changes.iter().find(|c| c.field == "risk_tier").unwrap();
+        assert_eq!((tier.from.as_str(), tier.to.as_str()), ("High", "Medium"));
+
+        // Reverting only tightens controls
+        assert!(diff_policies(&new, &old)
+            .iter()
+            .all(|c| !c.is_safety_regression));
+    }
+
+    #[test]
+    fn fact_fields_flipping_are_not_regressions() {
+        let mut old = policy();
+        old.uses_biosignals = true;
+        old.touches_indigenous_data = true;
+        old.consent_profile.fpic_granted = true;
+        old.population.includes_minors = true;
+        let new = policy();
+
+        let changes = diff_policies(&old, &new);
+        assert_eq!(changes.len(), 4, "{:?}", changes);
+        assert!(changes
+            .iter()
+            .all(|c| c.to == "false" && !c.is_safety_regression));
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context, Result};
+use clap::{Parser, Subcommand};
+use governance_healthcare::{
+    validate_healthcare_policy_with, HealthcareGovernancePolicy, JurisdictionRules,
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "governance-healthcare")]
+#[command(about = "Validate healthcare governance policies as a CI pipeline step")]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Debug, Subcommand)]
+enum Commands {
+    /// Validate a policy JSON file; exits nonzero on failure
+    Validate {
+        #[arg(long)]
+        policy: PathBuf,
+        /// Rule pack name (default, eu-ai-act, us-hipaa, cl-ley-20584)
+        #[arg(long, default_value = "default")]
+        jurisdiction: String,
+        /// Treat warnings as failures
+        #[arg(long)]
+        strict: bool,
+    },
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    match cli.command {
+        Commands::Validate {
+            policy,
+            jurisdiction,
+            strict,
+        } => {
+            let rules = JurisdictionRules::by_name(&jurisdiction).ok_or_else(|| {
+                let known: Vec<String> = JurisdictionRules::builtin()
+                    .into_iter()
+                    .map(|r| r.name)
+                    .collect();
+                anyhow!(
+                    "unknown jurisdiction {:?}; expected one of {}",
+                    jurisdiction,
+                    known.join(", ")
+                )
+            })?;
+            let raw = std::fs::read_to_string(&policy)
+                .with_context(|| format!("reading {}", policy.display()))?;
+            let parsed = HealthcareGovernancePolicy::from_json_str(&raw)
+                .with_context(|| format!("parsing {}", policy.display()))?;
+            let result = validate_healthcare_policy_with(&parsed, &rules);
+
+            for e in &result.errors {
+                println!("error: {e}");
+            }
+            for w in &result.warnings {
+                println!("warning: {w}");
+            }
+            let passed = if strict {
+                result.is_ok_strict()
+            } else {
+                result.is_ok()
+            };
+            println!(
+                "{}: {} ({} errors, {} warnings)",
+                policy.display(),
+                if passed { "ok" } else { "FAILED" },
+                result.errors.len(),
+                result.warnings.len()
+            );
+            if !passed {
+                std::process::exit(1);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! ISO 8601 durations (`P18M`, `P90D`, `P1Y6M`) for log-retention rules.
+//!
+//! Calendar units use the Gregorian averages: a year is 365.2425 days and a
+//! month is exactly a twelfth of that, so `P12M` and `P1Y` compare equal.
+
+use std::time::Duration;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: u64 = 7 * SECS_PER_DAY;
+const SECS_PER_YEAR: u64 = 31_556_952;
+const SECS_PER_MONTH: u64 = SECS_PER_YEAR / 12;
+
+/// Duration of `n` average Gregorian years.
+pub fn years(n: u64) -> Duration {
+    Duration::from_secs(n * SECS_PER_YEAR)
+}
+
+/// Human-readable form for messages: `"7 years"` for whole years, ISO 8601 otherwise.
+pub fn describe(duration: Duration) -> String {
+    match duration.as_secs() {
+        SECS_PER_YEAR => "1 year".to_string(),
+        secs if secs > 0 && secs % SECS_PER_YEAR == 0 => format!("{} years", secs / SECS_PER_YEAR),
+        _ => format_iso8601(duration),
+    }
+}
+
+/// Parse an ISO 8601 duration such as `P18M`, `P90D` or `P1Y2M3DT4H`.
+///
+/// Only whole-number components are accepted.
+pub fn parse_iso8601(input: &str) -> Result<Duration, String> {
+    let err = |why: &str| format!("invalid ISO 8601 duration {:?}: {}", input, why);
+    let rest = input
+        .strip_prefix('P')
+        .ok_or_else(|| err("must start with 'P'"))?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return Err(err("'T' must be followed by a time component")),
+        Some((date, time)) => (date, Some(time)),
+        None => (rest, None),
+    };
+    if date.is_empty() && time.is_none() {
+        return Err(err("no components"));
+    }
+
+    let mut secs: u64 = 0;
+    for (part, units) in [
+        (
+            date,
+            &[
+                ('Y', SECS_PER_YEAR),
+                ('M', SECS_PER_MONTH),
+                ('W', SECS_PER_WEEK),
+                ('D', SECS_PER_DAY),
+            ][..],
+        ),
+        (
+            time.unwrap_or(""),
+            &[('H', SECS_PER_HOUR), ('M', SECS_PER_MINUTE), ('S', 1)][..],
+        ),
+    ] {
+        let mut units = units.iter();
+        let mut digits = String::new();
+        for c in part.chars() {
+            if c.is_ascii_digit() {
+                digits.push(c);
+                continue;
+            }
+            // Designators must appear in order, each at most once.
+            let &(_, unit) = units
+                .by_ref()
+                .find(|(d, _)| *d == c)
+                .ok_or_else(|| err(&format!("unexpected '{}'", c)))?;
+            let n: u64 = digits
+                .parse()
+                .map_err(|_| err(&format!("missing number before '{}'", c)))?;
+            secs = n
+                .checked_mul(unit)
+                .and_then(|s| secs.checked_add(s))
+                .ok_or_else(|| err("overflow"))?;
+            digits.clear();
+        }
+        if !digits.is_empty() {
+            return Err(err("trailing number without a designator"));
+        }
+    }
+    Ok(Duration::from_secs(secs))
+}
+
+/// Render `duration` as an ISO 8601 duration, using the largest units that fit.
+pub fn format_iso8601(duration: Duration) -> String {
+    let mut secs = duration.as_secs();
+    let mut out = String::from("P");
+    for (unit, designator) in [
+        (SECS_PER_YEAR, 'Y'),
+        (SECS_PER_MONTH, 'M'),
+        (SECS_PER_DAY, 'D'),
+    ] {
+        if secs >= unit {
+            out.push_str(&format!("{}{}", secs / unit, designator));
+            secs %= unit;
+        }
+    }
+    if secs > 0 {
+        out.push('T');
+        for (unit, designator) in [(SECS_PER_HOUR, 'H'), (SECS_PER_MINUTE, 'M'), (1, 'S')] {
+            if secs >= unit {
+                out.push_str(&format!("{}{}", secs / unit, designator));
+                secs %= unit;
+            }
+        }
+    }
+    if out.len() == 1 {
+        out.push_str("0D");
+    }
+    out
+}
+
+/// Serde adapter for `Option<Duration>` stored as an ISO 8601 string.
+pub mod serde_iso8601 {
+    use std::time::Duration;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Option<Duration>, s: S) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(d) => s.serialize_some(&super::format_iso8601(*d)),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Duration>, D::Error> {
+        Option::<String>::deserialize(d)?
+            .map(|s| super::parse_iso8601(&s).map_err(D::Error::custom))
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_calendar_and_time_components() {
+        assert_eq!(parse_iso8601("P1Y").unwrap(), years(1));
+        assert_eq!(parse_iso8601("P12M").unwrap(), years(1));
+        assert_eq!(
+            parse_iso8601("P90D").unwrap(),
+            Duration::from_secs(90 * SECS_PER_DAY)
+        );
+        assert_eq!(
+            parse_iso8601("P2W").unwrap(),
+            Duration::from_secs(14 * SECS_PER_DAY)
+        );
+        assert_eq!(
+            parse_iso8601("PT36H").unwrap(),
+            Duration::from_secs(36 * SECS_PER_HOUR)
+        );
+        assert!(parse_iso8601("P18M").unwrap() < years(2));
+        assert_eq!(format_iso8601(parse_iso8601("P18M").unwrap()), "P1Y6M");
+        assert_eq!(format_iso8601(parse_iso8601("P1DT2M").unwrap()), "P1DT2M");
+        assert_eq!(describe(years(7)), "7 years");
+        assert_eq!(describe(parse_iso8601("P6M").unwrap()), "P6M");
+
+        for bad in ["18M", "P", "PT", "P1", "PM", "P1M1Y", "P1H", "P1.5Y"] {
+            assert!(parse_iso8601(bad).is_err(), "{}", bad);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Tamper-evident validation artifacts: HMAC-SHA256 over the result, the
+//! validator version and the signing time, via `morpheus-security`.
+
+use std::time::SystemTime;
+
+use morpheus_security::{hmac_sign, hmac_verify, SecurityError};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::PolicyValidationResult;
+
+/// Version of this validator, bound into every signature.
+pub const VALIDATOR_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[derive(Debug, Error)]
+pub enum SigningError {
+    #[error("security error: {0}")]
+    Security(#[from] SecurityError),
+    #[error("serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// A validation result with the attestation needed to verify it later.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignedValidationResult {
+    pub result: PolicyValidationResult,
+    pub validator_version: String,
+    /// Signing time, as RFC 3339 in JSON.
+    #[serde(with = "crate::rfc3339")]
+    pub signed_at: SystemTime,
+    /// Hex-encoded HMAC-SHA256 over the fields above.
+    pub signature: String,
+}
+
+/// The exact bytes covered by the signature.
+fn signing_payload(
+    result: &PolicyValidationResult,
+    validator_version: &str,
+    signed_at: SystemTime,
+) -> Result<Vec<u8>, SigningError> {
+    Ok(serde_json::to_vec(&(result, validator_version, signed_at))?)
+}
+
+/// Sign `result` as produced by this validator version, now.
+pub fn sign_result(
+    result: &PolicyValidationResult,
+    key: &[u8],
+) -> Result<SignedValidationResult, SigningError> {
+    let signed_at = SystemTime::now();
+    let payload = signing_payload(result, VALIDATOR_VERSION, signed_at)?;
+    let tag = hmac_sign(key, &payload)?;
+    Ok(SignedValidationResult {
+        result: result.clone(),
+        validator_version: VALIDATOR_VERSION.to_string(),
+        signed_at,
+        signature: tag.iter().map(|b| format!("{b:02x}")).collect(),
+    })
+}
+
+/// True if `signed` is unmodified and was signed with `key`.
+///
+/// A malformed signature verifies as false rather than erroring.
+pub fn verify_result(signed: &SignedValidationResult, key: &[u8]) -> Result<bool, SigningError> {
+    let Some(tag) = decode_hex(&signed.signature) else {
+        return Ok(false);
+    };
+    let payload = signing_payload(&signed.result, &signed.validator_version, signed.signed_at)?;
+    Ok(hmac_verify(key, &payload, &tag)?)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| match pair {
+            [_, _] => u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok(),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"ci-signing-key";
+
+    fn result() -> PolicyValidationResult {
+        PolicyValidationResult {
+            model_id: "model-1".to_string(),
+            ok: false,
+            errors: vec!["owner must not be empty".to_string()],
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn sign_verify_round_trip() {
+        let signed = sign_result(&result(), KEY).unwrap();
+        assert!(verify_result(&signed, KEY).unwrap());
+
+        // The artifact survives being stored as JSON
+        let json = serde_json::to_string(&signed).unwrap();
+        let signed_at = serde_json::to_value(&signed).unwrap()["signed_at"].clone();
+        assert!(signed_at.as_str().unwrap().ends_with('Z'), "{}", json);
+        let reloaded: SignedValidationResult = serde_json::from_str(&json).unwrap();
+        assert_eq!(reloaded, signed);
+        assert!(verify_result(&reloaded, KEY).unwrap());
+    }
+
+    #[test]
+    fn tampered_result_fails() {
+        let mut signed = sign_result(&result(), KEY).unwrap();
+        signed.result.ok = true;
+        signed.result.errors.clear();
+        assert!(!verify_result(&signed, KEY).unwrap());
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let signed = sign_result(&result(), KEY).unwrap();
+        assert!(!verify_result(&signed, b"another-key").unwrap());
+    }
+
+    #[test]
+    fn malformed_signature_verifies_false() {
+        let mut signed = sign_result(&result(), KEY).unwrap();
+        for bad in ["", "abc", "zz", "not hex at all"] {
+            signed.signature = bad.to_string();
+            assert!(!verify_result(&signed, KEY).unwrap(), "{:?}", bad);
+        }
+    }
+}
@@ -1,5 +1,6 @@
 use clap::{Parser, Subcommand};
-use morpheus_neuromorph_core::MorpheusEngine;
+use morpheus_neuromorph_core::{MorpheusEngine, ReversalAttempt, ReversalAuditSink};
+use std::sync::Arc;
 use tracing_subscriber::EnvFilter;
 
 #[derive(Parser, Debug)]
@@ -20,6 +21,23 @@ enum Commands {
     EnforceAction {
         /// Action name (e.g., upgrade, rollback)
         action: String,
+        /// Identity requesting the action, recorded if it is refused
+        #[arg(long)]
+        identity: Option<String>,
+    }
+}
+
+/// Emits refused reversal attempts as warn-level events for alerting.
+struct TracingAuditSink;
+
+impl ReversalAuditSink for TracingAuditSink {
+    fn record(&self, attempt: &ReversalAttempt) {
+        tracing::warn!(
+            action = %attempt.action,
+            attempted_at = %attempt.attempted_at,
+            identity = attempt.identity.as_deref().unwrap_or("unknown"),
+            "reversal attempt refused"
+        );
     }
 }
 
@@ -29,7 +47,9 @@ fn main() {
         .init();
 
     let cli = Cli::parse();
-    let engine = MorpheusEngine::new().expect("failed to initialize MorpheusEngine");
+    let engine = MorpheusEngine::new()
+        .expect("failed to initialize MorpheusEngine")
+        .with_audit_sink(Arc::new(TracingAuditSink));
 
     match cli.command {
         Commands::ShowConfig => {
@@ -41,11 +61,15 @@ fn main() {
             println!("{json}");
         }
         Commands::ExportEndpoints => {
-            engine.register_example_endpoints();
+            engine
+                .register_example_endpoints()
+                .expect("example endpoints failed to register");
             let json = engine.export_active_endpoints_json();
             println!("{}", serde_json::to_string_pretty(&json).unwrap());
         }
-        Commands::EnforceAction { action } => match engine.enforce_no_reversal(&action) {
+        Commands::EnforceAction { action, identity } => match engine
+            .enforce_no_reversal_for(&action, identity.as_deref())
+        {
             Ok(_) => {
                 println!("Action '{action}' is allowed for neuromorphic evolution.");
             }
@@ -3,10 +3,11 @@
 //! Binds Morpheus-Client evolution records to Bostrom addresses with ED25519
 //! signing and Googolswarm audit trail support.
 
-use ed25519_dalek::{Keypair, PublicKey, Signature, SigningKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use crate::types::audit::EvolutionAuditRecord;
 use crate::MorpheusError;
 use tracing::debug;
 
@@ -19,30 +20,97 @@ pub struct BostromDid {
     pub address: String,
 }
 
+/// Human-readable part of every Bostrom bech32 address
+pub const BOSTROM_HRP: &str = "bostrom";
+
+const BECH32_CHARSET: &str = "qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Data-part lengths (payload + 6-char checksum) for 20- and 32-byte addresses
+const BECH32_DATA_LENGTHS: [usize; 2] = [38, 58];
+
 impl BostromDid {
     /// Parse a DID string
     pub fn new(did: String) -> Result<Self, MorpheusError> {
-        if !did.starts_with("did:bostrom:") {
-            return Err(MorpheusError::DidError(
-                "Invalid DID format: must start with 'did:bostrom:'".to_string(),
-            ));
-        }
-        let address = did.strip_prefix("did:bostrom:").unwrap().to_string();
-        if address.is_empty() {
-            return Err(MorpheusError::DidError("Address cannot be empty".to_string()));
-        }
-        Ok(Self { did, address })
+        let address = did.strip_prefix("did:bostrom:").ok_or_else(|| {
+            MorpheusError::InvalidDid("must start with 'did:bostrom:'".to_string())
+        })?;
+        Self::from_address(address.to_string())
     }
 
-    /// Create a DID from an address
-    pub fn from_address(address: String) -> Self {
-        Self {
+    /// Create a DID from an address, validating and lowercasing it
+    pub fn from_address(address: String) -> Result<Self, MorpheusError> {
+        let address = validate_bostrom_address(&address)?;
+        Ok(Self {
             did: format!("did:bostrom:{}", address),
             address,
+        })
+    }
+
+    /// Parse either a bare address or a full `did:bostrom:` string
+    pub fn parse(input: &str) -> Result<Self, MorpheusError> {
+        let input = input.trim();
+        if input.starts_with("did:") {
+            Self::new(input.to_string())
+        } else {
+            Self::from_address(input.to_string())
         }
     }
 }
 
+/// Validate a Bostrom bech32 address (prefix, charset, length, checksum)
+/// and return its normalized lowercase form.
+pub fn validate_bostrom_address(address: &str) -> Result<String, MorpheusError> {
+    let invalid = |reason: &str| MorpheusError::InvalidDid(format!("{}: {}", reason, address));
+
+    if address.chars().any(|c| c.is_ascii_lowercase())
+        && address.chars().any(|c| c.is_ascii_uppercase())
+    {
+        return Err(invalid("mixed-case address"));
+    }
+    let normalized = address.to_ascii_lowercase();
+    let (hrp, data) = normalized
+        .rsplit_once('1')
+        .ok_or_else(|| invalid("missing bech32 separator"))?;
+    if hrp != BOSTROM_HRP {
+        return Err(invalid("expected 'bostrom' prefix"));
+    }
+    if !BECH32_DATA_LENGTHS.contains(&data.len()) {
+        return Err(invalid("unexpected address length"));
+    }
+
+    let mut values: Vec<u8> = hrp.bytes().map(|b| b >> 5).collect();
+    values.push(0);
+    values.extend(hrp.bytes().map(|b| b & 31));
+    for c in data.chars() {
+        let v = BECH32_CHARSET
+            .find(c)
+            .ok_or_else(|| invalid("invalid bech32 character"))?;
+        values.push(v as u8);
+    }
+    if bech32_polymod(&values) != 1 {
+        return Err(invalid("bech32 checksum mismatch"));
+    }
+    Ok(normalized)
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GEN: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+    let mut chk: u32 = 1;
+    for &v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x1ffffff) << 5) ^ v as u32;
+        for (i, g) in GEN.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// Algorithm recorded on audit records signed by `DidKeyPair::sign_audit_record`
+pub const SIGNATURE_ALGORITHM: &str = "Ed25519";
+
 /// An ED25519 signing keypair for a Bostrom DID
 pub struct DidKeyPair {
     /// The Bostrom DID
@@ -50,19 +118,20 @@ pub struct DidKeyPair {
     /// The ED25519 signing key
     signing_key: SigningKey,
     /// The public key (for verification)
-    pub public_key: PublicKey,
+    pub public_key: VerifyingKey,
 }
 
 impl DidKeyPair {
-    /// Generate a new keypair from random seed
+    /// Generate a new keypair from random seed, bound to a validated address
     pub fn generate(address: String) -> Result<Self, MorpheusError> {
+        let did = BostromDid::parse(&address)?;
         let mut rng = rand::thread_rng();
         let random_bytes: [u8; 32] = rng.gen();
         let signing_key = SigningKey::from_bytes(&random_bytes);
         let public_key = signing_key.verifying_key();
 
         Ok(Self {
-            did: BostromDid::from_address(address),
+            did,
             signing_key,
             public_key,
         })
@@ -80,6 +149,21 @@ impl DidKeyPair {
         Ok(hex::encode(signature.to_bytes()))
     }
 
+    /// Sign an audit record in place, recording the algorithm next to the signature
+    ///
+    /// The signature covers the record's compact JSON with `signature` unset
+    /// and `signature_algorithm` already filled in.
+    pub fn sign_audit_record(
+        &self,
+        record: &mut EvolutionAuditRecord,
+    ) -> Result<String, MorpheusError> {
+        record.signature = None;
+        record.signature_algorithm = Some(SIGNATURE_ALGORITHM.to_string());
+        let signature = self.sign_json(record)?;
+        record.signature = Some(signature.clone());
+        Ok(signature)
+    }
+
     /// Get hex-encoded public key
     pub fn public_key_hex(&self) -> String {
         hex::encode(self.public_key.as_bytes())
@@ -103,7 +187,7 @@ pub fn verify_signature(
 
     let mut pk_array = [0u8; 32];
     pk_array.copy_from_slice(&public_key_bytes);
-    let public_key = PublicKey::from_bytes(&pk_array)
+    let public_key = VerifyingKey::from_bytes(&pk_array)
         .map_err(|_| MorpheusError::CryptoError("Invalid public key".to_string()))?;
 
     let signature_bytes = hex::decode(signature_hex)
@@ -119,8 +203,30 @@ pub fn verify_signature(
     sig_array.copy_from_slice(&signature_bytes);
     let signature = Signature::from_bytes(&sig_array);
 
-    public_key.verify_strict(message, &signature).is_ok();
-    Ok(true)
+    Ok(public_key.verify_strict(message, &signature).is_ok())
+}
+
+/// Verify an audit record signed by `DidKeyPair::sign_audit_record`
+///
+/// Unsigned records and records signed with another algorithm are errors
+pub fn verify_audit_record(
+    public_key_hex: &str,
+    record: &EvolutionAuditRecord,
+) -> Result<bool, MorpheusError> {
+    let signature = record
+        .signature
+        .as_deref()
+        .ok_or_else(|| MorpheusError::CryptoError("Audit record is unsigned".to_string()))?;
+    if record.signature_algorithm.as_deref() != Some(SIGNATURE_ALGORITHM) {
+        return Err(MorpheusError::CryptoError(format!(
+            "Unsupported signature algorithm: {:?}",
+            record.signature_algorithm
+        )));
+    }
+    let mut unsigned = record.clone();
+    unsigned.signature = None;
+    let message = serde_json::to_string(&unsigned)?;
+    verify_signature(public_key_hex, message.as_bytes(), signature)
 }
 
 /// Compute SHA256 hash of data
@@ -140,21 +246,47 @@ pub fn compute_hash_json<T: Serialize>(object: &T) -> Result<String, MorpheusErr
 mod tests {
     use super::*;
 
+    const TEST_ADDRESS: &str = "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7";
+
     #[test]
     fn test_bostrom_did_creation() {
-        let did = BostromDid::from_address("bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string());
+        let did = BostromDid::from_address(TEST_ADDRESS.to_string()).unwrap();
         assert!(did.did.starts_with("did:bostrom:"));
     }
 
+    #[test]
+    fn test_did_validation_and_normalization() {
+        let upper = BostromDid::parse(&TEST_ADDRESS.to_ascii_uppercase()).unwrap();
+        assert_eq!(upper.address, TEST_ADDRESS);
+        assert_eq!(BostromDid::parse(&upper.did).unwrap(), upper);
+
+        for bad in [
+            "bostrom_test",
+            "cosmos18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7",
+            "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye8",
+            "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9y",
+            "Bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7",
+        ] {
+            assert!(
+                matches!(BostromDid::parse(bad), Err(MorpheusError::InvalidDid(_))),
+                "{bad}"
+            );
+        }
+        assert!(matches!(
+            DidKeyPair::generate("bostrom_test".to_string()),
+            Err(MorpheusError::InvalidDid(_))
+        ));
+    }
+
     #[test]
     fn test_keypair_generation() {
-        let keypair = DidKeyPair::generate("bostrom_test".to_string()).unwrap();
+        let keypair = DidKeyPair::generate(TEST_ADDRESS.to_string()).unwrap();
         assert!(!keypair.public_key_hex().is_empty());
     }
 
     #[test]
     fn test_signing_and_verification() {
-        let keypair = DidKeyPair::generate("bostrom_test".to_string()).unwrap();
+        let keypair = DidKeyPair::generate(TEST_ADDRESS.to_string()).unwrap();
         let message = b"test message";
         let signature = keypair.sign(message).unwrap();
         let public_key_hex = keypair.public_key_hex();
@@ -4,17 +4,23 @@
 //! into a unified decision framework.
 
 use crate::types::{
-    audit::{EvolutionAuditRecord, EvolutionOutcome},
-    corridor::EcoCorridorContext,
+    audit::{ActionType, EvolutionAuditRecord, EvolutionOutcome},
+    corridor::{EcoCorridorContext, EcoImpactMetrics},
     evidence::EvidenceBundle,
-    guards::{BciCeilingGuard, EnvelopeGuard, GuardDecision, RoHGuard},
-    policy::PolicyProfile,
+    guards::{
+        BciCeilingGuard, EnvelopeGuard, GuardDecision, GuardDecisionRecord,
+        RoHGuard,
+    },
+    policy::{FrozenPolicyProfile, PolicyRegistry},
 };
 use crate::MorpheusError;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 /// An evolution proposal to be evaluated
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EvolutionProposal {
     /// DID of the proposer
     pub did: String,
@@ -22,8 +28,12 @@ pub struct EvolutionProposal {
     pub corridor_context: EcoCorridorContext,
     /// Evidence bundle
     pub evidence_bundle: EvidenceBundle,
-    /// Description of the neuromorphic decision
+    /// Free-text description of the neuromorphic decision
     pub neuromorphic_decision: String,
+    /// Structured verb for the decision; must be on the `ActionType` allowlist.
+    /// Defaults to `Unspecified` when absent, which the engine rejects
+    #[serde(default)]
+    pub action_type: ActionType,
     /// Current BCI* value
     pub current_bci: f64,
     /// Proposed BCI* after evolution
@@ -42,107 +52,504 @@ pub struct EvolutionProposal {
     pub proposed_session_length: u32,
 }
 
+impl EvolutionProposal {
+    /// Magnitude of the proposed change: the larger of |ΔBCI*| and |ΔRoH|
+    ///
+    /// Compared against `biomech_policy.max_effect_size`, so a change that
+    /// stays under every ceiling can still be rejected for being too large.
+    pub fn effect_size(&self) -> f64 {
+        (self.proposed_bci - self.current_bci)
+            .abs()
+            .max((self.proposed_roh - self.current_roh).abs())
+    }
+
+    /// Rebuild the proposal an audit record was produced from
+    ///
+    /// Records without "after" values yield a no-change proposal. Duty cycle
+    /// and session length are not recorded and come back as zero. Fails with
+    /// `AuditError` if the record itself does not validate.
+    pub fn from_audit_record(record: &EvolutionAuditRecord) -> Result<Self, MorpheusError> {
+        record.validate().map_err(MorpheusError::AuditError)?;
+        Ok(Self {
+            did: record.did.clone(),
+            corridor_context: record.corridor_context.clone(),
+            evidence_bundle: record.evidence_bundle.clone(),
+            neuromorphic_decision: record.neuromorphic_decision.clone(),
+            action_type: record.action_type,
+            current_bci: record.bci_before,
+            proposed_bci: record.bci_after.unwrap_or(record.bci_before),
+            current_roh: record.roh_before,
+            proposed_roh: record.roh_after.unwrap_or(record.roh_before),
+            current_duty_cycle: 0.0,
+            proposed_duty_cycle: 0.0,
+            current_session_length: 0,
+            proposed_session_length: 0,
+        })
+    }
+}
+
+/// Read a JSON array of proposals, e.g. a regression corpus for `evaluate_batch`
+pub fn load_proposals(path: impl AsRef<Path>) -> Result<Vec<EvolutionProposal>, MorpheusError> {
+    let raw = std::fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&raw)?)
+}
+
+/// Outcome of one proposal in a batch evaluation
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchEntry {
+    /// Position of the proposal in the input
+    pub index: usize,
+    /// DID of the proposer
+    pub did: String,
+    /// Decision reached for this proposal
+    pub outcome: EvolutionOutcome,
+    /// Audit record ID, for allowed proposals
+    pub record_id: Option<String>,
+}
+
+/// JSON-serializable report produced by `ReconciliationEngine::evaluate_batch`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BatchReport {
+    /// Policy profile the batch was evaluated under
+    pub policy_profile: String,
+    /// Number of proposals evaluated
+    pub evaluated: usize,
+    /// Number of proposals allowed
+    pub allowed: usize,
+    /// Per-proposal outcomes, in input order
+    pub entries: Vec<BatchEntry>,
+}
+
+/// Turn a governance rejection into the outcome it represents
+///
+/// Guard, monotonicity and effect-size rejections are `Forbidden`; corridor,
+/// evidence, and policy failures are `Rejected`. Anything else is a real error.
+pub fn outcome_for_error(err: MorpheusError) -> Result<EvolutionOutcome, MorpheusError> {
+    match err {
+        e @ (MorpheusError::GuardRejection(_)
+        | MorpheusError::MonotonicityViolation(_)
+        | MorpheusError::EffectSizeExceeded(_)) => Ok(EvolutionOutcome::Forbidden(e.to_string())),
+        e @ (MorpheusError::CorridorViolation(_)
+        | MorpheusError::EvidenceInvalid(_)
+        | MorpheusError::PolicyError(_)) => Ok(EvolutionOutcome::Rejected(e.to_string())),
+        e => Err(e),
+    }
+}
+
+/// A proposal field that falls outside the active policy's static limits
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldIssue {
+    /// Name of the `EvolutionProposal` field, or `effect_size` for the
+    /// change spanning BCI* and RoH
+    pub field: &'static str,
+    /// Value supplied in the proposal
+    pub value: f64,
+    /// Policy limit it exceeds
+    pub limit: f64,
+    /// Human-readable explanation
+    pub message: String,
+}
+
+/// Error for a guard that returned `Forbid`; RoH rejections are monotonicity
+/// violations, the other guards' are plain guard rejections
+fn guard_error(guard: &str, decision: &GuardDecision) -> MorpheusError {
+    match guard {
+        "roh" => MorpheusError::MonotonicityViolation(format!("RoH guard rejected: {:?}", decision)),
+        "bci" => MorpheusError::GuardRejection(format!("BCI guard rejected: {:?}", decision)),
+        _ => MorpheusError::GuardRejection(format!("Envelope guard rejected: {:?}", decision)),
+    }
+}
+
+/// Narrative steps and the first failure of one traced evaluation
+struct CheckLog<'a> {
+    steps: &'a mut Vec<String>,
+    /// Metrics label and error of the first failed check
+    failure: Option<(&'static str, MorpheusError)>,
+}
+
+impl CheckLog<'_> {
+    /// Describe a pass/fail check; only the first failure is kept
+    fn check(&mut self, label: &'static str, step: String, result: Result<(), MorpheusError>) {
+        match result {
+            Ok(()) => self.steps.push(format!("{} ✓", step)),
+            Err(e) => {
+                self.steps.push(format!("{} ✗ ({})", step, e));
+                if self.failure.is_none() {
+                    self.failure = Some((label, e));
+                }
+            }
+        }
+    }
+
+    /// Describe a guard decision; cautions pass, `Forbid` fails
+    fn guard(&mut self, label: &'static str, step: String, decision: GuardDecision) {
+        let mark = match &decision {
+            GuardDecision::AllowFull => "✓".to_string(),
+            GuardDecision::DegradePrecision(msg) | GuardDecision::PauseAndRest(msg) => {
+                format!("⚠ {} ({})", decision.verdict(), msg)
+            }
+            GuardDecision::Forbid(reason) => format!("✗ ({})", reason),
+        };
+        self.steps.push(format!("{} {}", step, mark));
+        if matches!(decision, GuardDecision::Forbid(_)) && self.failure.is_none() {
+            self.failure = Some((label, guard_error(label, &decision)));
+        }
+    }
+}
+
+/// Step-0 allowlist check on a proposal's action type
+fn check_action(action_type: ActionType) -> Result<(), MorpheusError> {
+    if action_type.is_reversal() {
+        return Err(MorpheusError::MonotonicityViolation(format!(
+            "Reversal action {:?} is not permitted",
+            action_type
+        )));
+    }
+    if action_type == ActionType::Unspecified {
+        return Err(MorpheusError::PolicyError(
+            "Proposal declares no action type".to_string(),
+        ));
+    }
+    if !action_type.is_permitted() {
+        return Err(MorpheusError::PolicyError(format!(
+            "Action type {:?} is not on the allowlist",
+            action_type
+        )));
+    }
+    Ok(())
+}
+
+/// Width below which `ReconciliationEngine::approval_boundary` stops bisecting
+pub const BOUNDARY_TOLERANCE: f64 = 1e-6;
+
+/// Largest approvable value of one proposal field
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Boundary {
+    /// Largest approvable value, or `None` if no value in range is approved
+    pub max_approvable: Option<f64>,
+    /// Rejection just above the boundary; `None` if the whole range passes
+    pub blocked_by: Option<String>,
+}
+
+/// What-if report produced by `ReconciliationEngine::approval_boundary`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct BoundaryReport {
+    /// Sweep of `proposed_bci` with every other field held fixed
+    pub proposed_bci: Boundary,
+    /// Sweep of `proposed_roh` with every other field held fixed
+    pub proposed_roh: Boundary,
+}
+
+/// Corridor-level decision composed from the existing per-component verdicts
+#[derive(Clone, Debug, PartialEq)]
+pub struct CorridorVerdict {
+    /// True only when nothing below blocks the corridor
+    pub allow: bool,
+    /// Human-readable reason for each blocking component
+    pub blocking_reasons: Vec<String>,
+}
+
+/// Combine the reconciliation outcome, the microspace fleet report
+/// (`(microspace_id, decision)` pairs) and the corridor's eco-impact metrics.
+///
+/// Only `Forbid` decisions block; degrade/pause verdicts are left to the
+/// individual microspaces. The eco-impact side blocks when the metrics fail
+/// `EcoImpactMetrics::is_admissible`.
+pub fn corridor_verdict(
+    evolution: &EvolutionOutcome,
+    microspaces: &[(String, GuardDecision)],
+    eco_impact: &EcoImpactMetrics,
+) -> CorridorVerdict {
+    let mut blocking_reasons = Vec::new();
+
+    match evolution {
+        EvolutionOutcome::Allowed => {}
+        EvolutionOutcome::Rejected(r) => blocking_reasons.push(format!("evolution rejected: {}", r)),
+        EvolutionOutcome::Deferred(r) => blocking_reasons.push(format!("evolution deferred: {}", r)),
+        EvolutionOutcome::Forbidden(r) => blocking_reasons.push(format!("evolution forbidden: {}", r)),
+    }
+
+    for (microspace_id, decision) in microspaces {
+        if let GuardDecision::Forbid(r) = decision {
+            blocking_reasons.push(format!("microspace {}: {}", microspace_id, r));
+        }
+    }
+
+    if !eco_impact.is_admissible() {
+        blocking_reasons.push(format!(
+            "eco-impact inadmissible (composite risk {:.3})",
+            eco_impact.composite_risk()
+        ));
+    }
+
+    CorridorVerdict {
+        allow: blocking_reasons.is_empty(),
+        blocking_reasons,
+    }
+}
+
+/// Sink for governance decision counters.
+///
+/// Implementations typically forward to a metrics backend as
+/// `morpheus_proposals_total{outcome}` and `morpheus_guard_rejections_total{guard}`.
+pub trait ReconciliationMetrics: Send + Sync {
+    /// Increment `morpheus_proposals_total{outcome}` ("approved" or "rejected")
+    fn proposal_evaluated(&self, outcome: &str);
+    /// Increment `morpheus_guard_rejections_total{guard}`
+    fn guard_rejected(&self, guard: &str);
+}
+
+/// Fetches the policy profile governing a proposal, e.g. from a remote
+/// governance registry, for [`ReconciliationEngine::evaluate_evolution_async`]
+#[async_trait::async_trait]
+pub trait PolicyResolver: Send + Sync {
+    /// Resolve the profile to evaluate `proposal` against
+    async fn resolve_policy(
+        &self,
+        proposal: &EvolutionProposal,
+    ) -> Result<FrozenPolicyProfile, MorpheusError>;
+}
+
+/// Fetches the evidence bundle backing a proposal, e.g. by bundle id from a
+/// remote evidence store, for [`ReconciliationEngine::evaluate_evolution_async`]
+#[async_trait::async_trait]
+pub trait EvidenceResolver: Send + Sync {
+    /// Resolve the bundle that replaces `proposal.evidence_bundle`
+    async fn resolve_evidence(
+        &self,
+        proposal: &EvolutionProposal,
+    ) -> Result<EvidenceBundle, MorpheusError>;
+}
+
+/// Engine tunables that are not part of the policy profile
+///
+/// Serializable so deployments can persist and version it alongside their
+/// profile; `Default` matches [`ReconciliationEngine::new`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EngineConfig {
+    /// RoH ceiling enforced by the RoH guard (constitutional limit 0.3); the
+    /// profile's `biomech_policy.roh_ceiling` can only tighten it
+    pub roh_ceiling: f64,
+    /// Whether the envelope-tightening guard runs
+    pub envelope_guard_enabled: bool,
+    /// BCI* warn band start, as a fraction of the profile's `bci_ceiling`
+    pub bci_warn_fraction: f64,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            roh_ceiling: 0.3, // Hard constitutional ceiling
+            envelope_guard_enabled: true,
+            bci_warn_fraction: 0.85,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Validate the configuration; the RoH ceiling may only be tightened
+    pub fn validate(&self) -> Result<(), String> {
+        if !(self.roh_ceiling > 0.0 && self.roh_ceiling <= 0.3) {
+            return Err(format!(
+                "roh_ceiling must be in (0, 0.3], got {}",
+                self.roh_ceiling
+            ));
+        }
+        if !(0.0..=1.0).contains(&self.bci_warn_fraction) {
+            return Err(format!(
+                "bci_warn_fraction must be in [0, 1], got {}",
+                self.bci_warn_fraction
+            ));
+        }
+        Ok(())
+    }
+
+    fn bci_guard(&self, profile: &FrozenPolicyProfile) -> BciCeilingGuard {
+        let bci_ceiling = profile.biomech_policy.bci_ceiling;
+        BciCeilingGuard::new(bci_ceiling, (bci_ceiling * self.bci_warn_fraction).max(0.0))
+    }
+}
+
 /// The reconciliation engine
 pub struct ReconciliationEngine {
     /// Active policy profile
-    pub policy_profile: Arc<PolicyProfile>,
+    pub policy_profile: FrozenPolicyProfile,
     /// BCI ceiling guard
     pub bci_guard: BciCeilingGuard,
-    /// RoH guard (instantiated per proposal)
-    pub roh_ceiling: f64,
-    /// Envelope guard (instantiated per proposal)
-    pub envelope_guard_enabled: bool,
+    /// Tunables for the RoH and envelope guards (instantiated per proposal)
+    pub config: EngineConfig,
+    /// Optional metrics sink; no counters are recorded when unset
+    pub metrics: Option<Arc<dyn ReconciliationMetrics>>,
+    /// Consulted by `evaluate_evolution_async` in place of `policy_profile`
+    pub policy_resolver: Option<Arc<dyn PolicyResolver>>,
+    /// Consulted by `evaluate_evolution_async` in place of the proposal's bundle
+    pub evidence_resolver: Option<Arc<dyn EvidenceResolver>>,
 }
 
 impl ReconciliationEngine {
-    /// Create a new reconciliation engine
-    pub fn new(policy_profile: PolicyProfile) -> Result<Self, MorpheusError> {
-        policy_profile.validate().map_err(|e| MorpheusError::PolicyError(e))?;
+    /// Create a new reconciliation engine with the default [`EngineConfig`]
+    ///
+    /// Accepts a [`PolicyProfile`] (frozen on the way in) or an already
+    /// [`FrozenPolicyProfile`]; either way the rules cannot drift afterwards.
+    pub fn new(policy_profile: impl Into<FrozenPolicyProfile>) -> Result<Self, MorpheusError> {
+        Self::from_config(policy_profile, EngineConfig::default())
+    }
 
-        let bci_ceiling = policy_profile.biomech_policy.bci_ceiling;
-        let warn_threshold = (bci_ceiling * 0.85).max(0.0);
+    /// Create an engine with explicit tunables, e.g. loaded from a deployment's
+    /// versioned configuration
+    pub fn from_config(
+        policy_profile: impl Into<FrozenPolicyProfile>,
+        config: EngineConfig,
+    ) -> Result<Self, MorpheusError> {
+        let policy_profile = policy_profile.into();
+        policy_profile.validate().map_err(|e| MorpheusError::PolicyError(e))?;
+        config.validate().map_err(MorpheusError::PolicyError)?;
 
         Ok(Self {
-            policy_profile: Arc::new(policy_profile),
-            bci_guard: BciCeilingGuard::new(bci_ceiling, warn_threshold),
-            roh_ceiling: 0.3, // Hard constitutional ceiling
-            envelope_guard_enabled: true,
+            bci_guard: config.bci_guard(&policy_profile),
+            policy_profile,
+            config,
+            metrics: None,
+            policy_resolver: None,
+            evidence_resolver: None,
         })
     }
 
+    /// Create an engine for the latest registered version of profile `name`
+    pub fn from_registry(registry: &PolicyRegistry, name: &str) -> Result<Self, MorpheusError> {
+        let profile = registry
+            .latest(name)
+            .ok_or_else(|| MorpheusError::PolicyError(format!("No registered policy {}", name)))?;
+        Self::new(profile)
+    }
+
+    /// Attach a metrics sink for proposal/guard counters
+    pub fn with_metrics(mut self, metrics: Arc<dyn ReconciliationMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Resolve policy profiles remotely in `evaluate_evolution_async`
+    pub fn with_policy_resolver(mut self, resolver: Arc<dyn PolicyResolver>) -> Self {
+        self.policy_resolver = Some(resolver);
+        self
+    }
+
+    /// Resolve evidence bundles remotely in `evaluate_evolution_async`
+    pub fn with_evidence_resolver(mut self, resolver: Arc<dyn EvidenceResolver>) -> Self {
+        self.evidence_resolver = Some(resolver);
+        self
+    }
+
+    /// Record a rejection by `guard` and pass the error through
+    fn reject(&self, guard: &str, err: MorpheusError) -> MorpheusError {
+        if let Some(metrics) = &self.metrics {
+            metrics.guard_rejected(guard);
+            metrics.proposal_evaluated("rejected");
+        }
+        err
+    }
+
     /// Evaluate a complete evolution proposal
     pub fn evaluate_evolution(
         &self,
         proposal: &EvolutionProposal,
+    ) -> Result<(EvolutionOutcome, EvolutionAuditRecord), MorpheusError> {
+        self.evaluate_traced(proposal, &mut Vec::new())
+    }
+
+    /// The evaluation behind both `evaluate_evolution` and
+    /// `evaluate_with_narrative`, describing each check in `steps`
+    ///
+    /// Every check runs even after one fails, so the narrative is complete,
+    /// but the result and the metrics reflect only the first failure.
+    fn evaluate_traced(
+        &self,
+        proposal: &EvolutionProposal,
+        steps: &mut Vec<String>,
     ) -> Result<(EvolutionOutcome, EvolutionAuditRecord), MorpheusError> {
         info!(
             "Evaluating evolution proposal for DID: {}",
             proposal.did
         );
+        let mut checks = CheckLog {
+            steps,
+            failure: None,
+        };
+
+        // Step 0: Only allowlisted verbs may proceed; reversals are never evolution
+        checks.check(
+            "action",
+            format!("action {:?}", proposal.action_type),
+            check_action(proposal.action_type),
+        );
 
         // Step 1: Validate corridor context
-        proposal
-            .corridor_context
-            .validate()
-            .map_err(|e| MorpheusError::CorridorViolation(e))?;
+        checks.check(
+            "corridor",
+            format!("corridor {}", proposal.corridor_context.corridor_name),
+            proposal
+                .corridor_context
+                .validate()
+                .map_err(MorpheusError::CorridorViolation),
+        );
 
         // Step 2: Validate evidence bundle
-        proposal
-            .evidence_bundle
-            .validate()
-            .map_err(|e| MorpheusError::EvidenceInvalid(e))?;
-
-        // Step 3: Run BCI ceiling guard
-        debug!(
-            "Running BCI guard: current={}, proposed={}",
-            proposal.current_bci, proposal.proposed_bci
-        );
-        let bci_decision = self.bci_guard.evaluate(proposal.proposed_bci);
-        if matches!(bci_decision, GuardDecision::Forbid(_)) {
-            return Err(MorpheusError::GuardRejection(format!(
-                "BCI guard rejected: {:?}",
-                bci_decision
-            )));
-        }
-
-        // Step 4: Run RoH monotonicity guard
-        debug!(
-            "Running RoH guard: current={}, proposed={}",
-            proposal.current_roh, proposal.proposed_roh
+        checks.check(
+            "evidence",
+            format!("evidence {}", proposal.evidence_bundle.id),
+            self.check_evidence(&proposal.evidence_bundle)
+                .map_err(MorpheusError::EvidenceInvalid),
         );
-        let roh_guard = RoHGuard::new(self.roh_ceiling, proposal.current_roh);
-        let roh_decision = roh_guard.evaluate(proposal.proposed_roh);
-        if matches!(roh_decision, GuardDecision::Forbid(_)) {
-            return Err(MorpheusError::MonotonicityViolation(format!(
-                "RoH guard rejected: {:?}",
-                roh_decision
-            )));
-        }
-
-        // Step 5: Run envelope-tightening guard
-        if self.envelope_guard_enabled {
-            debug!("Running envelope guard");
-            let envelope_guard = EnvelopeGuard::new(proposal.current_duty_cycle, proposal.current_session_length);
-            let envelope_decision = envelope_guard.evaluate(proposal.proposed_duty_cycle, proposal.proposed_session_length);
-            if matches!(envelope_decision, GuardDecision::Forbid(_)) {
-                return Err(MorpheusError::GuardRejection(format!(
-                    "Envelope guard rejected: {:?}",
-                    envelope_decision
-                )));
-            }
+
+        // Steps 3-5: BCI ceiling, RoH monotonicity and envelope guards
+        let mut guard_records = Vec::new();
+        for (guard, decision) in self.guard_decisions(proposal) {
+            debug!("{} guard decision: {:?}", guard, decision);
+            guard_records.push(GuardDecisionRecord::new(guard, &decision));
+            checks.guard(guard, self.describe_guard(guard, proposal), decision);
+        }
+        if !self.config.envelope_guard_enabled {
+            checks.steps.push("envelope guard disabled".to_string());
         }
 
-        // Step 6: Check policy profile neurorights constraints
-        for constraint in &self.policy_profile.neurorights_constraints {
-            if constraint.enforced && constraint.name.contains("Forbidden") {
-                return Err(MorpheusError::PolicyError(format!(
+        // Step 6: Bound the magnitude of the change (NaN is never within)
+        let effect_size = proposal.effect_size();
+        let max_effect_size = self.policy_profile.biomech_policy.max_effect_size;
+        let within = effect_size <= max_effect_size;
+        checks.check(
+            "effect_size",
+            format!(
+                "effect size {:.3} {} max {}",
+                effect_size,
+                if within { "within" } else { "over" },
+                max_effect_size
+            ),
+            if within {
+                Ok(())
+            } else {
+                Err(MorpheusError::EffectSizeExceeded(format!(
+                    "effect size {} exceeds policy max_effect_size {}",
+                    effect_size, max_effect_size
+                )))
+            },
+        );
+
+        // Step 7: Check policy profile neurorights constraints
+        checks.check(
+            "policy",
+            format!("policy {} constraints", self.policy_profile.name),
+            match self.violated_constraint() {
+                Some(name) => Err(MorpheusError::PolicyError(format!(
                     "Policy constraint violated: {}",
-                    constraint.name
-                )));
-            }
-        }
+                    name
+                ))),
+                None => Ok(()),
+            },
+        );
 
-        // Step 7: Create audit record
+        // Step 8: Create audit record
         let mut audit_record = EvolutionAuditRecord::new(
             proposal.did.clone(),
             proposal.corridor_context.clone(),
@@ -150,6 +557,7 @@ impl ReconciliationEngine {
             self.policy_profile.name.clone(),
             proposal.neuromorphic_decision.clone(),
         );
+        audit_record.action_type = proposal.action_type;
 
         audit_record.set_outcome(
             EvolutionOutcome::Allowed,
@@ -158,26 +566,352 @@ impl ReconciliationEngine {
             proposal.current_roh,
             Some(proposal.proposed_roh),
         );
+        audit_record.guard_decisions = guard_records;
 
         // Verify monotonicity
-        if !audit_record.respects_monotonicity() {
-            return Err(MorpheusError::MonotonicityViolation(
-                "Audit record violates monotonicity constraint".to_string(),
-            ));
-        }
+        checks.check(
+            "monotonicity",
+            format!(
+                "BCI {}→{}, RoH {}→{} monotone",
+                proposal.current_bci,
+                proposal.proposed_bci,
+                proposal.current_roh,
+                proposal.proposed_roh
+            ),
+            if audit_record.respects_monotonicity() {
+                Ok(())
+            } else {
+                Err(MorpheusError::MonotonicityViolation(
+                    "Audit record violates monotonicity constraint".to_string(),
+                ))
+            },
+        );
 
+        if let Some((guard, err)) = checks.failure {
+            return Err(self.reject(guard, err));
+        }
+        if let Some(metrics) = &self.metrics {
+            metrics.proposal_evaluated("approved");
+        }
         info!("Evolution proposal APPROVED");
         Ok((EvolutionOutcome::Allowed, audit_record))
     }
 
+    /// Evidence checks from step 2: structure, policy-required domains, and age
+    fn check_evidence(&self, bundle: &EvidenceBundle) -> Result<(), String> {
+        for warning in bundle.validate()? {
+            warn!("{}", warning);
+        }
+        if let Some(missing) = self.policy_profile.missing_evidence_domains(bundle).first() {
+            return Err(format!(
+                "Missing evidence domain required by policy {}: {}",
+                self.policy_profile.name, missing
+            ));
+        }
+        self.policy_profile
+            .check_evidence_age(bundle, chrono::Utc::now())
+    }
+
+    /// First enforced neurorights constraint that forbids evolution, if any
+    fn violated_constraint(&self) -> Option<&str> {
+        self.policy_profile
+            .neurorights_constraints
+            .iter()
+            .find(|c| c.enforced && c.name.contains("Forbidden"))
+            .map(|c| c.name.as_str())
+    }
+
+    /// Evaluate `proposal` and explain the result in prose for reviewers
+    ///
+    /// The result is exactly that of [`ReconciliationEngine::evaluate_evolution`]
+    /// (including metrics), and the narrative is written by the same run. It
+    /// walks every check with its actual values, not just the first failure,
+    /// e.g. `"BCI 0.15 under ceiling 0.2 ✓; RoH 0.1→0.08 under ceiling 0.3 ✓; …"`,
+    /// and ends with the overall verdict.
+    pub fn evaluate_with_narrative(
+        &self,
+        proposal: &EvolutionProposal,
+    ) -> (
+        Result<(EvolutionOutcome, EvolutionAuditRecord), MorpheusError>,
+        String,
+    ) {
+        let mut steps = Vec::new();
+        let result = self.evaluate_traced(proposal, &mut steps);
+        steps.push(match &result {
+            Ok((outcome, _)) => format!("verdict {:?}", outcome),
+            Err(e) => format!("verdict Rejected: {}", e),
+        });
+        (result, steps.join("; "))
+    }
+
+    /// What `guard` compared for the narrative, without its verdict
+    fn describe_guard(&self, guard: &str, proposal: &EvolutionProposal) -> String {
+        let over = |value: f64, ceiling: f64| if value > ceiling { "over" } else { "under" };
+        match guard {
+            "bci" => format!(
+                "BCI {} {} ceiling {}",
+                proposal.proposed_bci,
+                over(proposal.proposed_bci, self.bci_guard.ceiling),
+                self.bci_guard.ceiling
+            ),
+            "roh" => format!(
+                "RoH {}→{} {} ceiling {}",
+                proposal.current_roh,
+                proposal.proposed_roh,
+                over(proposal.proposed_roh, self.roh_ceiling()),
+                self.roh_ceiling()
+            ),
+            _ => format!(
+                "envelope duty cycle {}→{}, session {}→{} min",
+                proposal.current_duty_cycle,
+                proposal.proposed_duty_cycle,
+                proposal.current_session_length,
+                proposal.proposed_session_length
+            ),
+        }
+    }
+
+    /// Each guard's decision for `proposal`, labelled as in the metrics
+    ///
+    /// This is the guard list [`ReconciliationEngine::evaluate_evolution`]
+    /// runs, in the same order. Read-only: records no metrics and does not
+    /// stop at the first `Forbid`, so callers can show every
+    /// `DegradePrecision`/`PauseAndRest` caution alongside the outcome.
+    pub fn guard_decisions(&self, proposal: &EvolutionProposal) -> Vec<(&'static str, GuardDecision)> {
+        let mut decisions = vec![
+            ("bci", self.bci_guard.evaluate(proposal.proposed_bci)),
+            (
+                "roh",
+                RoHGuard::new(self.roh_ceiling(), proposal.current_roh).evaluate(proposal.proposed_roh),
+            ),
+        ];
+        if self.config.envelope_guard_enabled {
+            let envelope_guard =
+                EnvelopeGuard::new(proposal.current_duty_cycle, proposal.current_session_length);
+            decisions.push((
+                "envelope",
+                envelope_guard.evaluate(proposal.proposed_duty_cycle, proposal.proposed_session_length),
+            ));
+        }
+        decisions
+    }
+
+    /// Cheap field-level check of a proposal against `biomech_policy` limits
+    ///
+    /// Runs no guards and records no metrics; intended for form validation
+    /// before calling [`ReconciliationEngine::evaluate_evolution`]. The effect
+    /// size spans two fields and is reported as `effect_size`.
+    pub fn precheck(&self, proposal: &EvolutionProposal) -> Vec<FieldIssue> {
+        let policy = &self.policy_profile.biomech_policy;
+        let checks = [
+            (
+                "proposed_duty_cycle",
+                proposal.proposed_duty_cycle,
+                policy.max_duty_cycle,
+                "duty cycle exceeds policy max_duty_cycle",
+            ),
+            (
+                "proposed_session_length",
+                f64::from(proposal.proposed_session_length),
+                f64::from(policy.max_session_minutes),
+                "session length exceeds policy max_session_minutes",
+            ),
+            (
+                "proposed_bci",
+                proposal.proposed_bci,
+                policy.bci_ceiling,
+                "BCI* exceeds policy bci_ceiling",
+            ),
+            (
+                "proposed_roh",
+                proposal.proposed_roh,
+                self.roh_ceiling(),
+                "RoH exceeds the constitutional ceiling",
+            ),
+            (
+                "effect_size",
+                proposal.effect_size(),
+                policy.max_effect_size,
+                "effect size exceeds policy max_effect_size",
+            ),
+        ];
+
+        checks
+            .into_iter()
+            .filter(|(_, value, limit, _)| value > limit)
+            .map(|(field, value, limit, message)| FieldIssue {
+                field,
+                value,
+                limit,
+                message: format!("{} ({} > {})", message, value, limit),
+            })
+            .collect()
+    }
+
+    /// Find the largest `proposed_bci` and, separately, `proposed_roh` that
+    /// this engine would still approve for `base_proposal`
+    ///
+    /// Each field is bisected over [current − `max_effect_size`, 1.0] to within
+    /// [`BOUNDARY_TOLERANCE`] using the full `evaluate_evolution` logic, which
+    /// assumes the approvable values form one interval (true for the built-in
+    /// guards). Evaluations are not counted in the engine's metrics.
+    pub fn approval_boundary(&self, base_proposal: &EvolutionProposal) -> BoundaryReport {
+        let engine = self.without_metrics();
+        let max_effect_size = self.policy_profile.biomech_policy.max_effect_size;
+        let sweep = |current: f64, set: &dyn Fn(&mut EvolutionProposal, f64)| {
+            let check = |value: f64| {
+                let mut proposal = base_proposal.clone();
+                set(&mut proposal, value);
+                engine.evaluate_evolution(&proposal).err().map(|e| e.to_string())
+            };
+            let mut lo = (current - max_effect_size).clamp(0.0, 1.0);
+            if let Some(reason) = check(lo) {
+                return Boundary {
+                    max_approvable: None,
+                    blocked_by: Some(reason),
+                };
+            }
+            let mut hi = 1.0;
+            let mut blocked_by = match check(hi) {
+                None => {
+                    return Boundary {
+                        max_approvable: Some(hi),
+                        blocked_by: None,
+                    }
+                }
+                Some(reason) => reason,
+            };
+            while hi - lo > BOUNDARY_TOLERANCE {
+                let mid = lo + (hi - lo) / 2.0;
+                match check(mid) {
+                    None => lo = mid,
+                    Some(reason) => {
+                        hi = mid;
+                        blocked_by = reason;
+                    }
+                }
+            }
+            Boundary {
+                max_approvable: Some(lo),
+                blocked_by: Some(blocked_by),
+            }
+        };
+
+        BoundaryReport {
+            proposed_bci: sweep(base_proposal.current_bci, &|p, v| p.proposed_bci = v),
+            proposed_roh: sweep(base_proposal.current_roh, &|p, v| p.proposed_roh = v),
+        }
+    }
+
+    /// A copy of this engine that records no metrics
+    fn without_metrics(&self) -> ReconciliationEngine {
+        ReconciliationEngine {
+            policy_profile: self.policy_profile.clone(),
+            bci_guard: self.bci_guard.clone(),
+            config: self.config.clone(),
+            metrics: None,
+            policy_resolver: None,
+            evidence_resolver: None,
+        }
+    }
+
+    /// Re-run a historical audit record under this engine's current profile
+    ///
+    /// The proposal is rebuilt with `EvolutionProposal::from_audit_record`, so
+    /// the envelope guard passes trivially and invalid records fail with
+    /// `AuditError`. Guard
+    /// and monotonicity rejections come back as `Forbidden`, other evaluation
+    /// failures as `Rejected`. Replays are not counted in the engine's metrics.
+    pub fn replay(&self, record: &EvolutionAuditRecord) -> Result<EvolutionOutcome, MorpheusError> {
+        let proposal = EvolutionProposal::from_audit_record(record)?;
+        match self.without_metrics().evaluate_evolution(&proposal) {
+            Ok((outcome, _)) => Ok(outcome),
+            Err(e) => outcome_for_error(e),
+        }
+    }
+
+    /// Evaluate every proposal and collect the outcomes into a report
+    ///
+    /// Governance rejections are recorded per entry (as in `replay`) rather
+    /// than aborting the batch; only unexpected errors are returned.
+    pub fn evaluate_batch(
+        &self,
+        proposals: &[EvolutionProposal],
+    ) -> Result<BatchReport, MorpheusError> {
+        let mut entries = Vec::with_capacity(proposals.len());
+        for (index, proposal) in proposals.iter().enumerate() {
+            let (outcome, record_id) = match self.evaluate_evolution(proposal) {
+                Ok((outcome, record)) => (outcome, Some(record.record_id)),
+                Err(e) => (outcome_for_error(e)?, None),
+            };
+            entries.push(BatchEntry {
+                index,
+                did: proposal.did.clone(),
+                outcome,
+                record_id,
+            });
+        }
+        Ok(BatchReport {
+            policy_profile: self.policy_profile.name.clone(),
+            evaluated: entries.len(),
+            allowed: entries
+                .iter()
+                .filter(|e| e.outcome == EvolutionOutcome::Allowed)
+                .count(),
+            entries,
+        })
+    }
+
+    /// Remaining BCI* margin below the active ceiling, clamped at 0
+    pub fn bci_headroom(&self, current_bci: f64) -> f64 {
+        (self.bci_guard.ceiling - current_bci).max(0.0)
+    }
+
+    /// RoH ceiling in force: the engine's, tightened by the profile's if lower
+    pub fn roh_ceiling(&self) -> f64 {
+        self.config
+            .roh_ceiling
+            .min(self.policy_profile.biomech_policy.roh_ceiling)
+    }
+
+    /// Remaining RoH margin below the engine's RoH ceiling, clamped at 0
+    pub fn roh_headroom(&self, current_roh: f64) -> f64 {
+        (self.roh_ceiling() - current_roh).max(0.0)
+    }
+
+    /// Evaluate a proposal after awaiting any configured resolvers
+    ///
+    /// The resolved evidence replaces the proposal's bundle and the resolved
+    /// profile is used for this call only; the guard logic itself is the
+    /// synchronous [`ReconciliationEngine::evaluate_evolution`]. With no
+    /// resolvers configured this is equivalent to the sync path.
+    pub async fn evaluate_evolution_async(
+        &self,
+        proposal: &EvolutionProposal,
+    ) -> Result<(EvolutionOutcome, EvolutionAuditRecord), MorpheusError> {
+        let mut resolved = proposal.clone();
+        if let Some(resolver) = &self.evidence_resolver {
+            resolved.evidence_bundle = resolver.resolve_evidence(proposal).await?;
+        }
+
+        let Some(resolver) = &self.policy_resolver else {
+            return self.evaluate_evolution(&resolved);
+        };
+        let profile = resolver.resolve_policy(proposal).await?;
+        let mut scoped = ReconciliationEngine::from_config(profile, self.config.clone())?;
+        scoped.metrics = self.metrics.clone();
+        scoped.evaluate_evolution(&resolved)
+    }
+
     /// Update the active policy profile
-    pub fn set_policy_profile(&mut self, profile: PolicyProfile) -> Result<(), MorpheusError> {
+    pub fn set_policy_profile(
+        &mut self,
+        profile: impl Into<FrozenPolicyProfile>,
+    ) -> Result<(), MorpheusError> {
+        let profile = profile.into();
         profile.validate().map_err(|e| MorpheusError::PolicyError(e))?;
-        self.policy_profile = Arc::new(profile);
-        self.bci_guard = BciCeilingGuard::new(
-            self.policy_profile.biomech_policy.bci_ceiling,
-            self.policy_profile.biomech_policy.bci_ceiling * 0.85,
-        );
+        self.bci_guard = self.config.bci_guard(&profile);
+        self.policy_profile = profile;
         Ok(())
     }
 }
@@ -186,9 +920,47 @@ impl ReconciliationEngine {
 mod tests {
     use super::*;
     use crate::types::corridor::EcoCorridorContext;
-    use crate::types::evidence::EvidenceBundle;
+    use crate::types::evidence::{BiophysicalDomains, EvidenceBundle};
     use crate::types::policy::PolicyProfile;
 
+    fn corridor() -> EcoCorridorContext {
+        let mut corridor = EcoCorridorContext::new("test".to_string(), "Test".to_string());
+        corridor.jurisdictions.push("EU".to_string());
+        corridor.eco_impact.corridor_safety = 0.9;
+        corridor
+    }
+
+    fn evidence() -> EvidenceBundle {
+        let mut evidence = EvidenceBundle::new("ev1".to_string(), 0.9, 0.1);
+        evidence.add_tag(BiophysicalDomains::atp());
+        evidence
+    }
+
+    /// A proposal that passes the corridor and evidence checks and leaves the
+    /// duty cycle and session length unchanged
+    fn proposal(
+        current_bci: f64,
+        proposed_bci: f64,
+        current_roh: f64,
+        proposed_roh: f64,
+    ) -> EvolutionProposal {
+        EvolutionProposal {
+            did: "did:bostrom:test".to_string(),
+            corridor_context: corridor(),
+            evidence_bundle: evidence(),
+            neuromorphic_decision: "test".to_string(),
+            action_type: ActionType::TightenEnvelope,
+            current_bci,
+            proposed_bci,
+            current_roh,
+            proposed_roh,
+            current_duty_cycle: 0.5,
+            proposed_duty_cycle: 0.5,
+            current_session_length: 60,
+            proposed_session_length: 60,
+        }
+    }
+
     #[test]
     fn test_reconciliation_engine_creation() {
         let profile = PolicyProfile::new("test".to_string(), "1.0".to_string(), "test".to_string());
@@ -196,32 +968,455 @@ mod tests {
         assert!(engine.is_ok());
     }
 
+    #[test]
+    fn test_engines_share_frozen_profile() {
+        let frozen = PolicyProfile::eu_neurorights().freeze();
+        let a = ReconciliationEngine::new(frozen.clone()).unwrap();
+        let b = ReconciliationEngine::new(frozen).unwrap();
+        assert_eq!(a.policy_profile.name, b.policy_profile.name);
+        assert_eq!(a.bci_headroom(0.1), b.bci_headroom(0.1));
+    }
+
+    #[test]
+    fn test_engine_from_registry_uses_latest() {
+        let mut registry = PolicyRegistry::new();
+        registry.insert(PolicyProfile::eu_neurorights()).unwrap();
+        let mut next = PolicyProfile::eu_neurorights();
+        next.version = "1.1".to_string();
+        next.biomech_policy.bci_ceiling = 0.18;
+        registry.insert(next).unwrap();
+
+        let engine = ReconciliationEngine::from_registry(&registry, "EU_neurorights").unwrap();
+        assert_eq!(engine.policy_profile.version, "1.1");
+        assert_eq!(engine.bci_guard.ceiling, 0.18);
+        assert!(ReconciliationEngine::from_registry(&registry, "missing").is_err());
+    }
+
+    #[test]
+    fn test_engine_config_round_trips_and_applies() {
+        let config: EngineConfig =
+            serde_json::from_str(r#"{ "roh_ceiling": 0.2, "envelope_guard_enabled": false }"#)
+                .unwrap();
+        assert_eq!(config.bci_warn_fraction, EngineConfig::default().bci_warn_fraction);
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<EngineConfig>(&json).unwrap(), config);
+
+        let engine =
+            ReconciliationEngine::from_config(PolicyProfile::eu_neurorights(), config).unwrap();
+        assert!((engine.roh_headroom(0.1) - 0.1).abs() < 1e-9);
+        let labels: Vec<_> = engine
+            .guard_decisions(&EvolutionProposal {
+                proposed_duty_cycle: 0.9,
+                ..proposal(0.1, 0.1, 0.1, 0.1)
+            })
+            .into_iter()
+            .map(|(label, _)| label)
+            .collect();
+        assert_eq!(labels, ["bci", "roh"]);
+
+        let loosened = EngineConfig {
+            roh_ceiling: 0.5,
+            ..EngineConfig::default()
+        };
+        assert!(matches!(
+            ReconciliationEngine::from_config(PolicyProfile::eu_neurorights(), loosened),
+            Err(MorpheusError::PolicyError(_))
+        ));
+    }
+
     #[test]
     fn test_evolution_proposal_evaluation() {
         let profile = PolicyProfile::new("test".to_string(), "1.0".to_string(), "test".to_string());
-        let mut engine = ReconciliationEngine::new(profile).unwrap();
-        
-        let mut corridor = EcoCorridorContext::new("test".to_string(), "Test".to_string());
-        corridor.jurisdictions.push("US/Arizona".to_string());
-        
-        let evidence = EvidenceBundle::new("ev1".to_string(), 0.9, 0.1);
-        
+        let engine = ReconciliationEngine::new(profile).unwrap();
+
         let proposal = EvolutionProposal {
-            did: "did:bostrom:test".to_string(),
-            corridor_context: corridor,
-            evidence_bundle: evidence,
-            neuromorphic_decision: "test".to_string(),
-            current_bci: 0.1,
-            proposed_bci: 0.15,
-            current_roh: 0.1,
-            proposed_roh: 0.12,
-            current_duty_cycle: 0.5,
             proposed_duty_cycle: 0.4,
-            current_session_length: 60,
             proposed_session_length: 45,
+            ..proposal(0.1, 0.08, 0.1, 0.09)
         };
 
         let result = engine.evaluate_evolution(&proposal);
-        assert!(result.is_ok());
+        assert!(result.is_ok(), "{:?}", result.err());
+    }
+
+    #[derive(Default)]
+    struct CountingMetrics {
+        rejected: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl ReconciliationMetrics for CountingMetrics {
+        fn proposal_evaluated(&self, _outcome: &str) {}
+
+        fn guard_rejected(&self, guard: &str) {
+            self.rejected.lock().unwrap().push(guard.to_string());
+        }
+    }
+
+    #[test]
+    fn test_metrics_record_guard_rejection() {
+        let profile = PolicyProfile::new("test".to_string(), "1.0".to_string(), "test".to_string());
+        let metrics = Arc::new(CountingMetrics::default());
+        let engine = ReconciliationEngine::new(profile)
+            .unwrap()
+            .with_metrics(metrics.clone());
+
+        // No jurisdictions: rejected by the corridor check
+        let mut proposal = proposal(0.1, 0.1, 0.1, 0.1);
+        proposal.corridor_context.jurisdictions.clear();
+
+        assert!(engine.evaluate_evolution(&proposal).is_err());
+        assert_eq!(*metrics.rejected.lock().unwrap(), vec!["corridor".to_string()]);
+    }
+
+    #[test]
+    fn test_empty_jurisdictions_rejected_by_engine() {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+        let mut proposal = proposal(0.1, 0.1, 0.1, 0.1);
+        proposal.corridor_context.jurisdictions.clear();
+
+        match engine.evaluate_evolution(&proposal) {
+            Err(MorpheusError::CorridorViolation(msg)) => assert!(msg.contains("jurisdiction"), "{}", msg),
+            other => panic!("expected corridor violation, got {:?}", other.map(|(o, _)| o)),
+        }
+    }
+
+    #[test]
+    fn test_headroom_tracks_active_profile() {
+        let mut engine = ReconciliationEngine::new(PolicyProfile::phoenix_medical()).unwrap();
+        assert!((engine.bci_headroom(0.15) - 0.10).abs() < 1e-9);
+        assert!((engine.roh_headroom(0.1) - 0.2).abs() < 1e-9);
+        assert_eq!(engine.roh_headroom(0.35), 0.0);
+
+        engine.set_policy_profile(PolicyProfile::eu_neurorights()).unwrap();
+        assert!((engine.bci_headroom(0.15) - 0.05).abs() < 1e-9);
+        assert_eq!(engine.bci_headroom(0.25), 0.0);
+    }
+
+    #[test]
+    fn test_guard_decisions_surface_cautions() {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+        // EU warn band starts at 0.17
+        let proposal = EvolutionProposal {
+            proposed_duty_cycle: 0.4,
+            ..proposal(0.19, 0.18, 0.1, 0.12)
+        };
+
+        let decisions = engine.guard_decisions(&proposal);
+        let labels: Vec<&str> = decisions.iter().map(|(l, _)| *l).collect();
+        assert_eq!(labels, ["bci", "roh", "envelope"]);
+        assert!(matches!(decisions[0].1, GuardDecision::DegradePrecision(_)));
+        assert!(matches!(decisions[1].1, GuardDecision::Forbid(_)));
+        assert_eq!(decisions[2].1, GuardDecision::AllowFull);
+    }
+
+    #[test]
+    fn test_corridor_verdict_collects_blocking_reasons() {
+        let eco = EcoImpactMetrics {
+            corridor_safety: 0.9,
+            ..Default::default()
+        };
+        let fleet = vec![
+            ("ms-1".to_string(), GuardDecision::AllowFull),
+            ("ms-2".to_string(), GuardDecision::PauseAndRest("near limit".to_string())),
+        ];
+        let verdict = corridor_verdict(&EvolutionOutcome::Allowed, &fleet, &eco);
+        assert!(verdict.allow);
+        assert!(verdict.blocking_reasons.is_empty());
+
+        let fleet = vec![("ms-3".to_string(), GuardDecision::forbid("density"))];
+        let verdict = corridor_verdict(
+            &EvolutionOutcome::Rejected("roh".to_string()),
+            &fleet,
+            &EcoImpactMetrics::default(),
+        );
+        assert!(!verdict.allow);
+        assert_eq!(verdict.blocking_reasons.len(), 3);
+        assert!(verdict.blocking_reasons[1].starts_with("microspace ms-3"));
+    }
+
+    #[test]
+    fn test_replay_under_stricter_profile() {
+        let mut corridor = corridor();
+        corridor.fpic_ids_status = crate::types::corridor::FpicIdsStatus::Granted;
+
+        let mut record = EvolutionAuditRecord::new(
+            "did:bostrom:test".to_string(),
+            corridor,
+            evidence(),
+            "legacy".to_string(),
+            "test".to_string(),
+        );
+        record.action_type = ActionType::AdjustCeiling;
+        record.set_outcome(EvolutionOutcome::Allowed, 0.23, Some(0.22), 0.1, Some(0.1));
+
+        // Phoenix ceiling is 0.25; the EU profile tightens it to 0.20
+        let mut engine = ReconciliationEngine::new(PolicyProfile::phoenix_medical()).unwrap();
+        let before = engine.replay(&record).unwrap();
+
+        engine.set_policy_profile(PolicyProfile::eu_neurorights()).unwrap();
+        let after = engine.replay(&record).unwrap();
+
+        assert_eq!(before, EvolutionOutcome::Allowed);
+        assert!(matches!(after, EvolutionOutcome::Forbidden(_)), "{:?}", after);
+    }
+
+    struct StaticEvidence(EvidenceBundle);
+
+    #[async_trait::async_trait]
+    impl EvidenceResolver for StaticEvidence {
+        async fn resolve_evidence(
+            &self,
+            _proposal: &EvolutionProposal,
+        ) -> Result<EvidenceBundle, MorpheusError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    struct StaticPolicy(FrozenPolicyProfile);
+
+    #[async_trait::async_trait]
+    impl PolicyResolver for StaticPolicy {
+        async fn resolve_policy(
+            &self,
+            _proposal: &EvolutionProposal,
+        ) -> Result<FrozenPolicyProfile, MorpheusError> {
+            Ok(self.0.clone())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_async_uses_resolvers() {
+        let proposal = EvolutionProposal {
+            // No tags: only valid once the resolver supplies the real bundle
+            evidence_bundle: EvidenceBundle::new("ev1".to_string(), 0.9, 0.1),
+            ..proposal(0.22, 0.22, 0.1, 0.1)
+        };
+
+        // Phoenix ceiling is 0.25
+        let engine = ReconciliationEngine::new(PolicyProfile::phoenix_medical())
+            .unwrap()
+            .with_evidence_resolver(Arc::new(StaticEvidence(evidence())));
+        assert!(engine.evaluate_evolution(&proposal).is_err());
+        let (outcome, _) = engine.evaluate_evolution_async(&proposal).await.unwrap();
+        assert_eq!(outcome, EvolutionOutcome::Allowed);
+
+        // The resolved EU profile tightens the ceiling to 0.20
+        let engine = engine.with_policy_resolver(Arc::new(StaticPolicy(
+            PolicyProfile::eu_neurorights().freeze(),
+        )));
+        assert!(matches!(
+            engine.evaluate_evolution_async(&proposal).await,
+            Err(MorpheusError::GuardRejection(_))
+        ));
+        assert_eq!(engine.policy_profile.name, "Phoenix_medical");
+    }
+
+    #[test]
+    fn test_proposal_from_audit_record() {
+        let mut record = EvolutionAuditRecord::new(
+            "did:bostrom:test".to_string(),
+            corridor(),
+            evidence(),
+            "EU_neurorights".to_string(),
+            "test".to_string(),
+        );
+        record.set_outcome(EvolutionOutcome::Allowed, 0.2, Some(0.15), 0.1, None);
+
+        let proposal = EvolutionProposal::from_audit_record(&record).unwrap();
+        assert_eq!(proposal.did, record.did);
+        assert_eq!((proposal.current_bci, proposal.proposed_bci), (0.2, 0.15));
+        assert_eq!((proposal.current_roh, proposal.proposed_roh), (0.1, 0.1));
+
+        record.did.clear();
+        assert!(matches!(
+            EvolutionProposal::from_audit_record(&record),
+            Err(MorpheusError::AuditError(_))
+        ));
+    }
+
+    #[test]
+    fn test_precheck_reports_out_of_bounds_fields() {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+        let proposal = EvolutionProposal {
+            current_duty_cycle: 0.9,
+            proposed_duty_cycle: 0.8,
+            current_session_length: 600,
+            proposed_session_length: 480,
+            ..proposal(0.1, 0.1, 0.1, 0.1)
+        };
+
+        let fields: Vec<_> = engine.precheck(&proposal).iter().map(|i| i.field).collect();
+        assert_eq!(fields, vec!["proposed_duty_cycle", "proposed_session_length"]);
+    }
+
+    #[test]
+    fn test_precheck_reports_excessive_effect_size() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        profile.biomech_policy.max_effect_size = 0.05;
+        let engine = ReconciliationEngine::new(profile).unwrap();
+
+        // Every field is within its own limit; only the 0.12 BCI* drop is too large
+        let mut proposal = proposal(0.15, 0.03, 0.1, 0.08);
+        let issues = engine.precheck(&proposal);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "effect_size");
+        assert!((issues[0].value - 0.12).abs() < 1e-9);
+        assert_eq!(issues[0].limit, 0.05);
+
+        proposal.proposed_bci = 0.12;
+        assert!(engine.precheck(&proposal).is_empty());
+    }
+
+    #[test]
+    fn test_required_evidence_domain_is_enforced() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        profile.required_evidence_domains.push("bio.thermal.v1".to_string());
+        let engine = ReconciliationEngine::new(profile).unwrap();
+        let mut proposal = proposal(0.1, 0.1, 0.1, 0.1);
+
+        match engine.evaluate_evolution(&proposal) {
+            Err(MorpheusError::EvidenceInvalid(msg)) => assert!(msg.contains("bio.thermal.v1")),
+            other => panic!("expected missing-domain rejection, got {:?}", other.map(|r| r.0)),
+        }
+
+        proposal.evidence_bundle.add_tag(BiophysicalDomains::thermal());
+        assert!(engine.evaluate_evolution(&proposal).is_ok());
+    }
+
+    #[test]
+    fn test_unlisted_and_reversal_actions_rejected() {
+        let metrics = Arc::new(CountingMetrics::default());
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights())
+            .unwrap()
+            .with_metrics(metrics.clone());
+
+        let mut proposal = EvolutionProposal {
+            neuromorphic_decision: "roll back last session's feedback gain".to_string(),
+            action_type: ActionType::Rollback,
+            ..proposal(0.1, 0.1, 0.1, 0.1)
+        };
+        assert!(matches!(
+            engine.evaluate_evolution(&proposal),
+            Err(MorpheusError::MonotonicityViolation(_))
+        ));
+
+        proposal.action_type = ActionType::Unknown;
+        assert!(matches!(
+            engine.evaluate_evolution(&proposal),
+            Err(MorpheusError::PolicyError(_))
+        ));
+        assert_eq!(*metrics.rejected.lock().unwrap(), vec!["action", "action"]);
+
+        proposal.action_type = ActionType::EnableFeedback;
+        let (_, record) = engine.evaluate_evolution(&proposal).unwrap();
+        assert_eq!(record.action_type, ActionType::EnableFeedback);
+    }
+
+    #[test]
+    fn test_proposal_without_action_type_is_rejected() {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+
+        // A proposal serialized before `action_type` existed
+        let mut legacy = serde_json::to_value(proposal(0.1, 0.1, 0.1, 0.1)).unwrap();
+        legacy.as_object_mut().unwrap().remove("action_type");
+        let legacy: EvolutionProposal = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.action_type, ActionType::Unspecified);
+
+        // Omitting the verb must not be a way around the allowlist
+        match engine.evaluate_evolution(&legacy) {
+            Err(MorpheusError::PolicyError(msg)) => assert!(msg.contains("no action type")),
+            other => panic!("expected PolicyError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_large_within_ceiling_change_exceeds_effect_size() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        profile.biomech_policy.max_effect_size = 0.05;
+        let engine = ReconciliationEngine::new(profile).unwrap();
+
+        // Both values stay under their ceilings, but BCI* drops by 0.12
+        let mut proposal = EvolutionProposal {
+            action_type: ActionType::AdjustCeiling,
+            ..proposal(0.15, 0.03, 0.1, 0.08)
+        };
+        assert!((proposal.effect_size() - 0.12).abs() < 1e-9);
+        match engine.evaluate_evolution(&proposal) {
+            Err(MorpheusError::EffectSizeExceeded(msg)) => assert!(msg.contains("0.05")),
+            other => panic!("expected effect-size rejection, got {:?}", other.map(|r| r.0)),
+        }
+
+        proposal.proposed_bci = 0.12;
+        assert!(engine.evaluate_evolution(&proposal).is_ok());
+    }
+
+    #[test]
+    fn test_approval_boundary_finds_bci_ceiling() {
+        let metrics = Arc::new(CountingMetrics::default());
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights())
+            .unwrap()
+            .with_metrics(metrics.clone());
+        let proposal = EvolutionProposal {
+            action_type: ActionType::AdjustCeiling,
+            ..proposal(0.25, 0.25, 0.1, 0.1)
+        };
+
+        let report = engine.approval_boundary(&proposal);
+        // EU ceiling is 0.20; RoH may not rise above its current value
+        let bci = report.proposed_bci.max_approvable.unwrap();
+        assert!(bci <= 0.20 && 0.20 - bci <= BOUNDARY_TOLERANCE, "{}", bci);
+        assert!(report.proposed_bci.blocked_by.unwrap().contains("BCI guard"));
+        let roh = report.proposed_roh.max_approvable;
+        assert!(roh.is_none(), "proposed_bci 0.25 is over the ceiling: {:?}", roh);
+
+        let within = EvolutionProposal {
+            proposed_bci: 0.18,
+            ..proposal
+        };
+        let roh = engine.approval_boundary(&within).proposed_roh;
+        assert!((roh.max_approvable.unwrap() - 0.1).abs() <= BOUNDARY_TOLERANCE);
+        assert!(metrics.rejected.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_narrative_walks_every_check() {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+        let proposal = proposal(0.16, 0.15, 0.1, 0.08);
+
+        let (result, narrative) = engine.evaluate_with_narrative(&proposal);
+        assert!(result.is_ok(), "{}", narrative);
+        assert!(narrative.contains("BCI 0.15 under ceiling 0.2 ✓"), "{}", narrative);
+        assert!(narrative.contains("RoH 0.1→0.08 under ceiling 0.3 ✓"), "{}", narrative);
+        assert!(narrative.ends_with("verdict Allowed"), "{}", narrative);
+
+        let rising = EvolutionProposal {
+            proposed_roh: 0.14,
+            ..proposal
+        };
+        let (result, narrative) = engine.evaluate_with_narrative(&rising);
+        assert!(result.is_err());
+        assert!(narrative.contains("RoH 0.1→0.14 under ceiling 0.3 ✗"), "{}", narrative);
+        // Checks after the first failure are still narrated
+        assert!(narrative.contains("effect size"), "{}", narrative);
+        assert!(narrative.contains("verdict Rejected: "), "{}", narrative);
+    }
+
+    #[test]
+    fn test_evidence_age_limit_rejects_stale_bundle() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        profile.max_evidence_age_days = Some(365);
+        let engine = ReconciliationEngine::new(profile).unwrap();
+
+        let mut proposal = proposal(0.1, 0.1, 0.1, 0.1);
+        proposal.evidence_bundle.created_at =
+            (chrono::Utc::now() - chrono::Duration::days(5 * 365)).to_rfc3339();
+
+        match engine.evaluate_evolution(&proposal) {
+            Err(MorpheusError::EvidenceInvalid(msg)) => assert!(msg.contains("days old")),
+            other => panic!("expected stale-evidence rejection, got {:?}", other.map(|r| r.0)),
+        }
+
+        proposal.evidence_bundle.created_at = chrono::Utc::now().to_rfc3339();
+        assert!(engine.evaluate_evolution(&proposal).is_ok());
     }
 }
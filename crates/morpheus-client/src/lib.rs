@@ -23,9 +23,9 @@ pub use core::{evolution::EvolutionClient, reconciliation::ReconciliationEngine}
 pub use types::{
     audit::EvolutionAuditRecord,
     corridor::EcoCorridorContext,
-    evidence::EvidenceBundle,
+    evidence::{EvidenceBundle, EvidenceBundleBuilder},
     guards::{BciCeilingGuard, RoHGuard},
-    policy::PolicyProfile,
+    policy::{FrozenPolicyProfile, PolicyProfile, PolicyRegistry},
 };
 
 /// Library version
@@ -52,15 +52,25 @@ pub enum MorpheusError {
     #[error("Guard rejection: {0}")]
     GuardRejection(String),
 
+    /// Proposal changes BCI*/RoH by more than the policy's `max_effect_size`
+    #[error("Effect size exceeded: {0}")]
+    EffectSizeExceeded(String),
+
     #[error("Audit record error: {0}")]
     AuditError(String),
 
     #[error("Bostrom DID error: {0}")]
     DidError(String),
 
+    #[error("Invalid Bostrom DID: {0}")]
+    InvalidDid(String),
+
     #[error("Serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
 
+    #[error("I/O error: {0}")]
+    IoError(#[from] std::io::Error),
+
     #[error("Cryptographic error: {0}")]
     CryptoError(String),
 
@@ -1,56 +1,84 @@
 //! Morpheus_Client CLI: demonstration and testing interface
+//!
+//! Prints a human-readable demo report by default (`--pretty`). Use `--json`
+//! to emit the demo's outcome, audit record and signature as one JSON object,
+//! or `--batch <proposals.json>` to evaluate a JSON array of proposals under
+//! the EU profile and print a JSON report. The demo modes exit with the code
+//! of the worst guard decision, so CI can tell warnings from a forbid.
 
+use clap::Parser;
 use morpheus_client::{
     bostrom::did_integration::{BostromDid, DidKeyPair},
-    core::reconciliation::{EvolutionProposal, ReconciliationEngine},
+    core::reconciliation::{
+        load_proposals, outcome_for_error, EvolutionProposal, ReconciliationEngine,
+    },
     types::{
+        audit::ActionType,
         corridor::{EcoCorridorContext, EcoImpactMetrics, FpicIdsStatus},
         evidence::{BiophysicalDomains, EvidenceBundle},
+        guards::{ExitCodePolicy, GuardDecision},
         policy::PolicyProfile,
     },
     MorpheusError, Result, VERSION,
 };
 use std::io::{self, Write};
+use std::path::PathBuf;
 use tracing::{info, Level};
 use tracing_subscriber::FmtSubscriber;
 
+const DEMO_ADDRESS: &str = "bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7";
+
+#[derive(Parser, Debug)]
+#[command(name = "morpheus-client", version = VERSION, about = "Morpheus_Client demo and batch evaluator")]
+struct Cli {
+    /// Evaluate a JSON array of proposals under the EU profile and print a JSON report
+    #[arg(long, value_name = "PROPOSALS_JSON")]
+    batch: Option<PathBuf>,
+
+    /// Emit the demo outcome, audit record and signature as a single JSON object
+    #[arg(long, conflicts_with = "pretty")]
+    json: bool,
+
+    /// Print the decorated human-readable demo report (the default)
+    #[arg(long)]
+    pretty: bool,
+}
+
 fn main() -> Result<()> {
-    // Initialize tracing
-    let subscriber = FmtSubscriber::builder()
+    let cli = Cli::parse();
+
+    // Logs go to stderr so stdout stays parseable in --json and --batch modes
+    FmtSubscriber::builder()
         .with_max_level(Level::INFO)
+        .with_writer(io::stderr)
         .init();
 
-    println!("\n╔═════════════════════════════════════════════════════════════╗");
-    println!("║  Morpheus_Client v{}                                ║", VERSION);
-    println!("║  Sovereign Neuromorphic Evolution Framework                 ║");
-    println!("║  with EvolutionAuditRecords & RoH/BCI* Monotonicity       ║");
-    println!("╚═════════════════════════════════════════════════════════════╝\n");
-
-    // Example 1: Create a Bostrom DID
-    println!("[ Step 1: Creating Bostrom DID ]");
-    let keypair =
-        DidKeyPair::generate("bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string())?;
-    println!("✓ DID: {}", keypair.did.did);
-    println!("✓ Public Key: {}\n", keypair.public_key_hex());
+    if let Some(path) = cli.batch {
+        let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights())?;
+        let report = engine.evaluate_batch(&load_proposals(path)?)?;
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
 
-    // Example 2: Set up policy profile
-    println!("[ Step 2: Loading Policy Profile ]");
-    let policy = PolicyProfile::eu_neurorights();
-    println!("✓ Profile: {} ({})", policy.name, policy.authority);
-    println!("✓ BCI Ceiling: {}", policy.biomech_policy.bci_ceiling);
-    println!(
-        "✓ Neurorights Constraints: {}",
-        policy.neurorights_constraints.len()
-    );
-    println!();
+    let code = if cli.json { print_json()? } else { print_pretty()? };
+    if code != 0 {
+        std::process::exit(code);
+    }
+    Ok(())
+}
 
-    // Example 3: Create reconciliation engine
-    println!("[ Step 3: Initializing Reconciliation Engine ]");
-    let engine = ReconciliationEngine::new(policy)?;
-    println!("✓ Engine initialized with policy constraints\n");
+/// Map the worst decision across an evaluation to a process exit code.
+///
+/// Lets CI distinguish "passed with warnings" from "forbidden"; an empty set
+/// of decisions exits 0.
+fn exit_code_for_decisions(decisions: &[(&str, GuardDecision)], policy: &ExitCodePolicy) -> i32 {
+    GuardDecision::worst(decisions.iter().map(|(_, d)| d))
+        .map(|d| d.exit_code_with(policy))
+        .unwrap_or(0)
+}
 
-    // Example 4: Propose evolution
-    println!("[ Step 4: Proposing Neuromorphic Evolution ]");
+/// The sample corridor proposal both demo modes evaluate
+fn demo_proposal(did: &str) -> EvolutionProposal {
     let mut corridor = EcoCorridorContext::new(
         "phoenix_medical_001".to_string(),
         "Phoenix Medical Corridor".to_string(),
@@ -70,21 +98,86 @@ fn main() -> Result<()> {
     evidence.add_tag(BiophysicalDomains::thermal());
     evidence.add_tag(BiophysicalDomains::autonomic());
 
-    let proposal = EvolutionProposal {
-        did: keypair.did.did.clone(),
+    EvolutionProposal {
+        did: did.to_string(),
         corridor_context: corridor,
         evidence_bundle: evidence,
-        neuromorphic_decision: "Enable advanced brain-computer interface with somatosensory feedback"
+        neuromorphic_decision: "Tighten brain-computer interface somatosensory feedback envelope"
             .to_string(),
-        current_bci: 0.12,
+        action_type: ActionType::TightenEnvelope,
+        // Still inside the EU BCI* warn band, so the BCI guard cautions
+        current_bci: 0.19,
         proposed_bci: 0.18,
         current_roh: 0.10,
-        proposed_roh: 0.14,
+        proposed_roh: 0.08,
         current_duty_cycle: 0.40,
         proposed_duty_cycle: 0.35,
         current_session_length: 90,
         proposed_session_length: 75,
+    }
+}
+
+/// `--json`: one object with `outcome`, `audit_record` and `signature`.
+/// A forbidden or rejected proposal reports that outcome with null record and
+/// signature; internal errors (crypto, serialization) fail the process instead.
+fn print_json() -> Result<i32> {
+    let keypair = DidKeyPair::generate(DEMO_ADDRESS.to_string())?;
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights())?;
+    let proposal = demo_proposal(&keypair.did.did);
+    let code =
+        exit_code_for_decisions(&engine.guard_decisions(&proposal), &ExitCodePolicy::default());
+
+    let output = match engine.evaluate_evolution(&proposal) {
+        Ok((outcome, mut audit_record)) => {
+            let signature = keypair.sign_audit_record(&mut audit_record)?;
+            serde_json::json!({
+                "outcome": outcome,
+                "audit_record": audit_record,
+                "signature": signature,
+            })
+        }
+        Err(e) => serde_json::json!({
+            "outcome": outcome_for_error(e)?,
+            "audit_record": null,
+            "signature": null,
+        }),
     };
+    println!("{}", serde_json::to_string(&output)?);
+    Ok(code)
+}
+
+fn print_pretty() -> Result<i32> {
+    println!("\n╔═════════════════════════════════════════════════════════════╗");
+    println!("║  Morpheus_Client v{}                                ║", VERSION);
+    println!("║  Sovereign Neuromorphic Evolution Framework                 ║");
+    println!("║  with EvolutionAuditRecords & RoH/BCI* Monotonicity       ║");
+    println!("╚═════════════════════════════════════════════════════════════╝\n");
+
+    // Example 1: Create a Bostrom DID
+    println!("[ Step 1: Creating Bostrom DID ]");
+    let keypair = DidKeyPair::generate(DEMO_ADDRESS.to_string())?;
+    println!("✓ DID: {}", keypair.did.did);
+    println!("✓ Public Key: {}\n", keypair.public_key_hex());
+
+    // Example 2: Set up policy profile
+    println!("[ Step 2: Loading Policy Profile ]");
+    let policy = PolicyProfile::eu_neurorights();
+    println!("✓ Profile: {} ({})", policy.name, policy.authority);
+    println!("✓ BCI Ceiling: {}", policy.biomech_policy.bci_ceiling);
+    println!(
+        "✓ Neurorights Constraints: {}",
+        policy.neurorights_constraints.len()
+    );
+    println!();
+
+    // Example 3: Create reconciliation engine
+    println!("[ Step 3: Initializing Reconciliation Engine ]");
+    let engine = ReconciliationEngine::new(policy)?;
+    println!("✓ Engine initialized with policy constraints\n");
+
+    // Example 4: Propose evolution
+    println!("[ Step 4: Proposing Neuromorphic Evolution ]");
+    let proposal = demo_proposal(&keypair.did.did);
 
     println!("✓ Evolution proposal created:");
     println!("  - Decision: {}", proposal.neuromorphic_decision);
@@ -94,9 +187,34 @@ fn main() -> Result<()> {
 
     // Example 5: Evaluate proposal
     println!("[ Step 5: Evaluating Proposal Against Guards ]");
+    let decisions = engine.guard_decisions(&proposal);
+    for (guard, decision) in &decisions {
+        match decision {
+            GuardDecision::AllowFull => println!("  - {} guard: allow", guard),
+            GuardDecision::DegradePrecision(reason) => {
+                println!("  ! {} guard: degrade precision ({})", guard, reason)
+            }
+            GuardDecision::PauseAndRest(reason) => {
+                println!("  ! {} guard: pause and rest ({})", guard, reason)
+            }
+            GuardDecision::Forbid(reason) => println!("  ✗ {} guard: forbid ({})", guard, reason),
+        }
+    }
     match engine.evaluate_evolution(&proposal) {
-        Ok((outcome, audit_record)) => {
-            println!("✓ Proposal APPROVED");
+        Ok((outcome, mut audit_record)) => {
+            let cautions: Vec<&str> = decisions
+                .iter()
+                .filter(|(_, d)| d.is_caution())
+                .map(|(guard, _)| *guard)
+                .collect();
+            if cautions.is_empty() {
+                println!("✓ Proposal APPROVED ({:?})", outcome);
+            } else {
+                println!(
+                    "✓ Proposal APPROVED with conditions from: {}",
+                    cautions.join(", ")
+                );
+            }
             println!("  - Record ID: {}", audit_record.record_id);
             println!("  - Policy: {}", audit_record.policy_profile);
             println!("  - Monotonicity respected: {}", audit_record.respects_monotonicity());
@@ -108,7 +226,7 @@ fn main() -> Result<()> {
                 Ok(json) => {
                     println!("✓ Audit record serialized ({}bytes)", json.len());
                     // Sign it
-                    let signature = keypair.sign_json(&audit_record)?;
+                    let signature = keypair.sign_audit_record(&mut audit_record)?;
                     println!("✓ Cryptographic signature: {}...", &signature[..32]);
                     println!();
 
@@ -138,5 +256,28 @@ fn main() -> Result<()> {
     println!("║  - RoH/BCI* Monotonicity: Hard mathematical constraints   ║");
     println!("╚═════════════════════════════════════════════════════════════╝\n");
 
-    Ok(())
+    Ok(exit_code_for_decisions(&decisions, &ExitCodePolicy::default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_follows_worst_decision() {
+        let policy = ExitCodePolicy {
+            warn_code: 2,
+            forbid_code: 3,
+        };
+        assert_eq!(exit_code_for_decisions(&[], &policy), 0);
+
+        let mut decisions = vec![
+            ("bci", GuardDecision::AllowFull),
+            ("roh", GuardDecision::DegradePrecision("near ceiling".to_string())),
+        ];
+        assert_eq!(exit_code_for_decisions(&decisions, &policy), 2);
+
+        decisions.push(("envelope", GuardDecision::forbid("duty cycle")));
+        assert_eq!(exit_code_for_decisions(&decisions, &policy), 3);
+    }
 }
@@ -1,5 +1,47 @@
-use crate::types::guards::{GuardDecision, SafetyGuard};
+use crate::types::guards::{ForbidReason, GuardDecision};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Literature key for the built-in ceilings, suffixed with `#<table>.<key>`
+pub const NANOSWARM_BASELINE_CITATION: &str = "morpheus.nanoswarm.baseline.v1";
+
+/// A limit together with the source that justifies it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Ceiling<T> {
+    /// The limit itself
+    pub value: T,
+    /// DOI, PMID, or literature key backing `value`
+    pub citation: String,
+}
+
+impl<T> Ceiling<T> {
+    /// A ceiling of `value` sourced from `citation`
+    pub fn new(value: T, citation: impl Into<String>) -> Self {
+        Self {
+            value,
+            citation: citation.into(),
+        }
+    }
+}
+
+/// Built-in table entries, cited to the nanoswarm baseline
+fn baseline_table<T>(table: &str, entries: &[(&str, T)]) -> HashMap<String, Ceiling<T>>
+where
+    T: Copy,
+{
+    entries
+        .iter()
+        .map(|(key, value)| {
+            (
+                key.to_string(),
+                Ceiling::new(
+                    *value,
+                    format!("{}#{}.{}", NANOSWARM_BASELINE_CITATION, table, key),
+                ),
+            )
+        })
+        .collect()
+}
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MicrospaceState {
@@ -18,59 +60,152 @@ pub struct SwarmActivityProposal {
     pub activity_type: String,
 }
 
+/// Fractions of each limit at which the guard starts cautioning
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CautionRatios {
+    /// Density above `ceiling * density` degrades precision
+    pub density: f64,
+    /// Power above `limit * power` requests a pause
+    pub power: f64,
+    /// Occupancy above `limit * duration` requests a pause
+    pub duration: f64,
+}
+
+impl Default for CautionRatios {
+    fn default() -> Self {
+        Self {
+            density: 0.8,
+            power: 0.7,
+            duration: 0.8,
+        }
+    }
+}
+
 pub struct MicrospaceIntegrityGuard {
-    /// Max density for each organism type (evidence-backed, %)
-    pub density_ceilings: std::collections::HashMap<String, f64>,
-    /// Max activity power for each ecosystem role (mW)
-    pub activity_power_limits: std::collections::HashMap<String, f64>,
-    /// Max occupancy time for each organism (secs)
-    pub occupancy_limits: std::collections::HashMap<String, u64>,
+    /// Max density for each organism type (%), with its citation
+    pub density_ceilings: HashMap<String, Ceiling<f64>>,
+    /// Max activity power for each ecosystem role (mW), with its citation
+    pub activity_power_limits: HashMap<String, Ceiling<f64>>,
+    /// Max occupancy time for each organism (secs), with its citation
+    pub occupancy_limits: HashMap<String, Ceiling<u64>>,
+    /// Forbid unknown organisms/roles instead of applying fallback ceilings
+    pub strict_unknown: bool,
+    /// Where caution bands begin, as fractions of each limit
+    pub caution_ratios: CautionRatios,
 }
 
 impl MicrospaceIntegrityGuard {
     pub fn new() -> Self {
-        let mut density_ceilings = std::collections::HashMap::new();
-        density_ceilings.insert("soil_rhizosphere".to_string(), 0.5);
-        density_ceilings.insert("insect_thorax".to_string(), 0.1);
-        density_ceilings.insert("coral_zooxanthella".to_string(), 0.05);
-        density_ceilings.insert("neural_tissue".to_string(), 0.01);
-
-        let mut activity_power_limits = std::collections::HashMap::new();
-        activity_power_limits.insert("nutrient_cycling".to_string(), 10.0);
-        activity_power_limits.insert("flight_metabolic".to_string(), 1.0);
-        activity_power_limits.insert("photosynthesis".to_string(), 0.5);
-
-        let mut occupancy_limits = std::collections::HashMap::new();
-        occupancy_limits.insert("soil_rhizosphere".to_string(), 3600); // 60 min
-        occupancy_limits.insert("insect_thorax".to_string(), 1800); // 30 min
-        occupancy_limits.insert("coral_zooxanthella".to_string(), 14400); // 4 hrs
-        occupancy_limits.insert("neural_tissue".to_string(), 7200); // 2 hrs
+        let density_ceilings = baseline_table(
+            "density",
+            &[
+                ("soil_rhizosphere", 0.5),
+                ("insect_thorax", 0.1),
+                ("coral_zooxanthella", 0.05),
+                ("neural_tissue", 0.01),
+            ],
+        );
+
+        let activity_power_limits = baseline_table(
+            "power",
+            &[
+                ("nutrient_cycling", 10.0),
+                ("flight_metabolic", 1.0),
+                ("photosynthesis", 0.5),
+            ],
+        );
+
+        let occupancy_limits = baseline_table(
+            "occupancy",
+            &[
+                ("soil_rhizosphere", 3600),    // 60 min
+                ("insect_thorax", 1800),       // 30 min
+                ("coral_zooxanthella", 14400), // 4 hrs
+                ("neural_tissue", 7200),       // 2 hrs
+            ],
+        );
 
         Self {
             density_ceilings,
             activity_power_limits,
             occupancy_limits,
+            strict_unknown: false,
+            caution_ratios: CautionRatios::default(),
+        }
+    }
+
+    /// Override the caution bands, e.g. for earlier warnings on sensitive organisms
+    pub fn with_caution_ratios(mut self, ratios: CautionRatios) -> Self {
+        self.caution_ratios = ratios;
+        self
+    }
+
+    /// Enable or disable strict handling of unknown organisms/roles
+    pub fn with_strict_unknown(mut self, strict: bool) -> Self {
+        self.strict_unknown = strict;
+        self
+    }
+
+    /// Look up an evidence-backed limit; in strict mode an unknown key is a Forbid
+    ///
+    /// Dotted keys fall back to progressively shorter prefixes, so
+    /// "nutrient_cycling.nitrogen" inherits the "nutrient_cycling" limit
+    /// unless it declares its own. The lenient fallback is cited as such.
+    fn limit_for<T: Clone>(
+        &self,
+        limits: &HashMap<String, Ceiling<T>>,
+        key: &str,
+        kind: &str,
+        fallback: T,
+    ) -> Result<Ceiling<T>, GuardDecision> {
+        let mut candidate = key;
+        let found = loop {
+            if let Some(limit) = limits.get(candidate) {
+                break Some(limit.clone());
+            }
+            match candidate.rfind('.') {
+                Some(idx) => candidate = &candidate[..idx],
+                None => break None,
+            }
+        };
+        match found {
+            Some(limit) => Ok(limit),
+            None if self.strict_unknown => Err(GuardDecision::forbid(format!(
+                "unknown {} {} — no evidence-backed ceiling",
+                kind, key
+            ))),
+            None => Ok(Ceiling::new(
+                fallback,
+                format!("uncited fallback for unknown {} {}", kind, key),
+            )),
         }
     }
 
     pub fn evaluate_density(&self, state: &MicrospaceState) -> GuardDecision {
-        let ceiling = self
-            .density_ceilings
-            .get(&state.occupant_organism)
-            .copied()
-            .unwrap_or(0.1);
+        let ceiling = match self.limit_for(
+            &self.density_ceilings,
+            &state.occupant_organism,
+            "organism",
+            0.1,
+        ) {
+            Ok(ceiling) => ceiling,
+            Err(decision) => return decision,
+        };
 
         let current_density_pct = (state.current_swarm_volume_mm3 / state.volume_mm3) * 100.0;
 
-        if current_density_pct > ceiling {
-            GuardDecision::Forbid(format!(
-                "Microspace {} density {:.2}% exceeds ceiling {:.2}%",
-                state.microspace_id, current_density_pct, ceiling
-            ))
-        } else if current_density_pct > ceiling * 0.8 {
+        if current_density_pct > ceiling.value {
+            GuardDecision::Forbid(
+                ForbidReason::new(format!(
+                    "Microspace {} density {:.2}% exceeds ceiling {:.2}%",
+                    state.microspace_id, current_density_pct, ceiling.value
+                ))
+                .with_evidence_ref(Some(ceiling.citation)),
+            )
+        } else if current_density_pct > ceiling.value * self.caution_ratios.density {
             GuardDecision::DegradePrecision(format!(
-                "Microspace {} density approaching ceiling {:.2}%/{:.2}%",
-                state.microspace_id, current_density_pct, ceiling
+                "Microspace {} density approaching ceiling {:.2}%/{:.2}% [evidence {}]",
+                state.microspace_id, current_density_pct, ceiling.value, ceiling.citation
             ))
         } else {
             GuardDecision::AllowFull
@@ -82,23 +217,31 @@ impl MicrospaceIntegrityGuard {
         state: &MicrospaceState,
         proposal: &SwarmActivityProposal,
     ) -> GuardDecision {
-        let limit = self
-            .activity_power_limits
-            .get(&state.ecosystem_role)
-            .copied()
-            .unwrap_or(1.0);
-
-        if proposal.proposed_energy_draw_mw > limit {
-            GuardDecision::Forbid(format!(
-                "Activity {} draws {:.2} mW exceeds limit {:.2} mW for {}",
-                proposal.activity_type,
-                proposal.proposed_energy_draw_mw,
-                limit,
-                state.ecosystem_role
-            ))
-        } else if proposal.proposed_energy_draw_mw > limit * 0.7 {
+        let limit = match self.limit_for(
+            &self.activity_power_limits,
+            &state.ecosystem_role,
+            "ecosystem role",
+            1.0,
+        ) {
+            Ok(limit) => limit,
+            Err(decision) => return decision,
+        };
+
+        if proposal.proposed_energy_draw_mw > limit.value {
+            GuardDecision::Forbid(
+                ForbidReason::new(format!(
+                    "Activity {} draws {:.2} mW exceeds limit {:.2} mW for {}",
+                    proposal.activity_type,
+                    proposal.proposed_energy_draw_mw,
+                    limit.value,
+                    state.ecosystem_role
+                ))
+                .with_evidence_ref(Some(limit.citation)),
+            )
+        } else if proposal.proposed_energy_draw_mw > limit.value * self.caution_ratios.power {
             GuardDecision::PauseAndRest(format!(
-                "Activity power approaching limit; consider reducing duty cycle"
+                "Activity power approaching limit; consider reducing duty cycle [evidence {}]",
+                limit.citation
             ))
         } else {
             GuardDecision::AllowFull
@@ -110,20 +253,30 @@ impl MicrospaceIntegrityGuard {
         state: &MicrospaceState,
         proposal: &SwarmActivityProposal,
     ) -> GuardDecision {
-        let limit = self
-            .occupancy_limits
-            .get(&state.occupant_organism)
-            .copied()
-            .unwrap_or(3600);
-
-        if proposal.proposed_duration_secs > limit {
-            GuardDecision::Forbid(format!(
-                "Proposed occupancy {} secs exceeds limit {} secs for {}",
-                proposal.proposed_duration_secs, limit, state.occupant_organism
-            ))
-        } else if proposal.proposed_duration_secs > (limit as f64 * 0.8) as u64 {
+        let limit = match self.limit_for(
+            &self.occupancy_limits,
+            &state.occupant_organism,
+            "organism",
+            3600,
+        ) {
+            Ok(limit) => limit,
+            Err(decision) => return decision,
+        };
+
+        if proposal.proposed_duration_secs > limit.value {
+            GuardDecision::Forbid(
+                ForbidReason::new(format!(
+                    "Proposed occupancy {} secs exceeds limit {} secs for {}",
+                    proposal.proposed_duration_secs, limit.value, state.occupant_organism
+                ))
+                .with_evidence_ref(Some(limit.citation)),
+            )
+        } else if proposal.proposed_duration_secs
+            > (limit.value as f64 * self.caution_ratios.duration) as u64
+        {
             GuardDecision::PauseAndRest(format!(
-                "Occupancy near limit; consider retreat soon"
+                "Occupancy near limit; consider retreat soon [evidence {}]",
+                limit.citation
             ))
         } else {
             GuardDecision::AllowFull
@@ -177,13 +330,27 @@ mod tests {
             microspace_id: "soil_001".to_string(),
             occupant_organism: "soil_rhizosphere".to_string(),
             volume_mm3: 1000.0,
-            current_swarm_volume_mm3: 10.0, // 1% density
+            current_swarm_volume_mm3: 2.0, // 0.2% density, inside the 0.5% band
             ecosystem_role: "nutrient_cycling".to_string(),
         };
         let result = guard.evaluate_density(&state);
         assert!(matches!(result, GuardDecision::AllowFull));
     }
 
+    #[test]
+    fn test_soil_rhizosphere_one_percent_forbidden() {
+        let guard = MicrospaceIntegrityGuard::new();
+        let state = MicrospaceState {
+            microspace_id: "soil_001".to_string(),
+            occupant_organism: "soil_rhizosphere".to_string(),
+            volume_mm3: 1000.0,
+            current_swarm_volume_mm3: 10.0, // 1% density, twice the 0.5% ceiling
+            ecosystem_role: "nutrient_cycling".to_string(),
+        };
+        let result = guard.evaluate_density(&state);
+        assert!(matches!(result, GuardDecision::Forbid(_)));
+    }
+
     #[test]
     fn test_density_exceeded() {
         let guard = MicrospaceIntegrityGuard::new();
@@ -217,4 +384,97 @@ mod tests {
         let result = guard.evaluate_activity(&state, &proposal);
         assert!(matches!(result, GuardDecision::Forbid(_)));
     }
+
+    #[test]
+    fn test_unknown_organism_strict_vs_lenient() {
+        let state = MicrospaceState {
+            microspace_id: "x_001".to_string(),
+            occupant_organism: "soil_rhizosphere_typo".to_string(),
+            volume_mm3: 1000.0,
+            current_swarm_volume_mm3: 0.1,
+            ecosystem_role: "nutrient_cycling".to_string(),
+        };
+        let lenient = MicrospaceIntegrityGuard::new();
+        assert!(matches!(lenient.evaluate_density(&state), GuardDecision::AllowFull));
+
+        let strict = MicrospaceIntegrityGuard::new().with_strict_unknown(true);
+        assert!(matches!(strict.evaluate_density(&state), GuardDecision::Forbid(_)));
+    }
+
+    #[test]
+    fn test_tightened_density_ratio_warns_sooner() {
+        let state = MicrospaceState {
+            microspace_id: "soil_001".to_string(),
+            occupant_organism: "soil_rhizosphere".to_string(),
+            volume_mm3: 1000.0,
+            current_swarm_volume_mm3: 3.0, // 0.3% density, 60% of the 0.5% ceiling
+            ecosystem_role: "nutrient_cycling".to_string(),
+        };
+        let default = MicrospaceIntegrityGuard::new();
+        assert!(matches!(default.evaluate_density(&state), GuardDecision::AllowFull));
+
+        let sensitive = MicrospaceIntegrityGuard::new().with_caution_ratios(CautionRatios {
+            density: 0.5,
+            ..CautionRatios::default()
+        });
+        assert!(matches!(
+            sensitive.evaluate_density(&state),
+            GuardDecision::DegradePrecision(_)
+        ));
+    }
+
+    #[test]
+    fn test_sub_role_inherits_parent_limit() {
+        let state = MicrospaceState {
+            microspace_id: "soil_002".to_string(),
+            occupant_organism: "soil_rhizosphere".to_string(),
+            volume_mm3: 1000.0,
+            current_swarm_volume_mm3: 0.1,
+            ecosystem_role: "nutrient_cycling.nitrogen.fixation".to_string(),
+        };
+        let proposal = SwarmActivityProposal {
+            target_microspace_id: "soil_002".to_string(),
+            proposed_energy_draw_mw: 5.0, // allowed under the 10 mW parent limit
+            proposed_duration_secs: 600,
+            activity_type: "nitrogen_fixation_assist".to_string(),
+        };
+        let strict = MicrospaceIntegrityGuard::new().with_strict_unknown(true);
+        assert_eq!(strict.evaluate_activity(&state, &proposal), GuardDecision::AllowFull);
+
+        let mut specialized = MicrospaceIntegrityGuard::new();
+        specialized.activity_power_limits.insert(
+            "nutrient_cycling.nitrogen".to_string(),
+            Ceiling::new(2.0, "test:nitrogen"),
+        );
+        assert!(matches!(
+            specialized.evaluate_activity(&state, &proposal),
+            GuardDecision::Forbid(_)
+        ));
+    }
+
+    #[test]
+    fn test_forbid_and_caution_carry_citation() {
+        let guard = MicrospaceIntegrityGuard::new();
+        let mut state = MicrospaceState {
+            microspace_id: "soil_001".to_string(),
+            occupant_organism: "soil_rhizosphere".to_string(),
+            volume_mm3: 1000.0,
+            current_swarm_volume_mm3: 100.0,
+            ecosystem_role: "nutrient_cycling".to_string(),
+        };
+        let citation = "morpheus.nanoswarm.baseline.v1#density.soil_rhizosphere";
+        match guard.evaluate_density(&state) {
+            GuardDecision::Forbid(reason) => {
+                assert_eq!(reason.evidence_ref.as_deref(), Some(citation));
+                assert!(reason.to_string().contains(citation));
+            }
+            other => panic!("expected Forbid, got {:?}", other),
+        }
+
+        state.current_swarm_volume_mm3 = 4.5; // 0.45%, inside the caution band
+        match guard.evaluate_density(&state) {
+            GuardDecision::DegradePrecision(msg) => assert!(msg.contains(citation)),
+            other => panic!("expected DegradePrecision, got {:?}", other),
+        }
+    }
 }
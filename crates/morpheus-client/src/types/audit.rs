@@ -3,8 +3,11 @@
 //! Logs every neuromorphic decision with evidence, consent, corridor context,
 //! and applied policy profile, creating a DID-bound, forward-only audit trail.
 
-use crate::types::{corridor::EcoCorridorContext, evidence::EvidenceBundle};
+use crate::types::{
+    corridor::EcoCorridorContext, evidence::EvidenceBundle, guards::GuardDecisionRecord,
+};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 /// Outcome of an evolution decision evaluation
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -19,6 +22,104 @@ pub enum EvolutionOutcome {
     Forbidden(String),
 }
 
+/// Structured verb classifying a neuromorphic decision
+///
+/// Only the allowlisted verbs (see [`ActionType::is_permitted`]) can be
+/// approved. Reversal verbs are kept so such proposals are still classified
+/// and audited; any unrecognised verb deserializes to `Unknown`. A missing
+/// verb deserializes to `Unspecified` so records written before action types
+/// existed still load, but the engine rejects it like any unlisted verb.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum ActionType {
+    /// Enable closed-loop feedback within the current envelope
+    EnableFeedback,
+    /// Adjust a BCI*/RoH ceiling (the guards still enforce monotonicity)
+    AdjustCeiling,
+    /// Tighten the duty-cycle/session envelope
+    TightenEnvelope,
+    /// Roll back a previously enacted evolution
+    Rollback,
+    /// Downgrade an already granted capability
+    Downgrade,
+    /// No verb declared (records that predate action types); never approved
+    #[default]
+    Unspecified,
+    /// Unrecognised verb
+    #[serde(other)]
+    Unknown,
+}
+
+impl ActionType {
+    /// Verbs a proposal may declare and still be approved
+    pub const PERMITTED: [ActionType; 3] = [
+        ActionType::EnableFeedback,
+        ActionType::AdjustCeiling,
+        ActionType::TightenEnvelope,
+    ];
+
+    /// Whether this verb is on the allowlist
+    pub fn is_permitted(self) -> bool {
+        Self::PERMITTED.contains(&self)
+    }
+
+    /// Whether this verb would undo earlier evolution
+    pub fn is_reversal(self) -> bool {
+        matches!(self, ActionType::Rollback | ActionType::Downgrade)
+    }
+}
+
+/// How a redacted field is handled on export
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RedactionAction {
+    /// Keep the key, replace the value with `"[REDACTED]"`
+    Mask,
+    /// Remove the key entirely
+    Drop,
+}
+
+/// A single redaction, addressed by dotted path (e.g. `evidence_bundle.provenance`)
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionRule {
+    /// Dotted path into the serialized record
+    pub path: String,
+    /// What to do with the field
+    pub action: RedactionAction,
+}
+
+/// Fields to redact before sharing audit records externally
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct RedactionPolicy {
+    /// Rules applied in order
+    pub rules: Vec<RedactionRule>,
+}
+
+impl RedactionPolicy {
+    /// Add a masking rule
+    pub fn mask(mut self, path: &str) -> Self {
+        self.rules.push(RedactionRule {
+            path: path.to_string(),
+            action: RedactionAction::Mask,
+        });
+        self
+    }
+
+    /// Add a drop rule
+    pub fn drop_field(mut self, path: &str) -> Self {
+        self.rules.push(RedactionRule {
+            path: path.to_string(),
+            action: RedactionAction::Drop,
+        });
+        self
+    }
+
+    /// Preset for external auditors: masks the DID and drops evidence provenance
+    pub fn external_auditor() -> Self {
+        Self::default()
+            .mask("did")
+            .drop_field("evidence_bundle.provenance")
+    }
+}
+
 /// Represents a complete evolution audit record entry
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct EvolutionAuditRecord {
@@ -36,6 +137,9 @@ pub struct EvolutionAuditRecord {
     pub policy_profile: String,
     /// Description of the neuromorphic decision
     pub neuromorphic_decision: String,
+    /// Structured verb the proposal declared
+    #[serde(default)]
+    pub action_type: ActionType,
     /// Outcome of the evaluation
     pub outcome: EvolutionOutcome,
     /// BCI* value before decision
@@ -46,8 +150,15 @@ pub struct EvolutionAuditRecord {
     pub roh_before: f64,
     /// RoH value after decision (if allowed)
     pub roh_after: Option<f64>,
+    /// Every guard decision reached during evaluation, in evaluation order
+    #[serde(default)]
+    pub guard_decisions: Vec<GuardDecisionRecord>,
     /// Cryptographic signature (hex-encoded)
     pub signature: Option<String>,
+    /// Algorithm that produced `signature` (e.g. "Ed25519"); absent on
+    /// records signed before the algorithm was recorded
+    #[serde(default)]
+    pub signature_algorithm: Option<String>,
     /// Non-actuating artifacts related to this decision
     pub non_actuating_artifacts: Vec<String>,
 }
@@ -69,12 +180,15 @@ impl EvolutionAuditRecord {
             evidence_bundle,
             policy_profile,
             neuromorphic_decision,
+            action_type: ActionType::Unspecified,
             outcome: EvolutionOutcome::Rejected("Not yet evaluated".to_string()),
             bci_before: 0.0,
             bci_after: None,
             roh_before: 0.0,
             roh_after: None,
+            guard_decisions: Vec::new(),
             signature: None,
+            signature_algorithm: None,
             non_actuating_artifacts: Vec::new(),
         }
     }
@@ -128,6 +242,58 @@ impl EvolutionAuditRecord {
         serde_json::to_string_pretty(self)
     }
 
+    /// SHA-256 (hex) over the compact JSON of the unredacted record
+    pub fn content_hash(&self) -> Result<String, serde_json::Error> {
+        let json = serde_json::to_string(self)?;
+        Ok(hex::encode(Sha256::digest(json.as_bytes())))
+    }
+
+    /// Serialize with `policy` applied, for sharing outside the trust boundary
+    ///
+    /// Output is `{ "record": ..., "redaction": { "original_sha256", "redacted_fields" } }`.
+    /// The hash covers the original record, so holders of the full record can
+    /// still tie the export back to the audit chain. Only fields actually
+    /// present are listed as redacted.
+    pub fn to_redacted_json(&self, policy: &RedactionPolicy) -> Result<String, serde_json::Error> {
+        let original_sha256 = self.content_hash()?;
+        let mut value = serde_json::to_value(self)?;
+        let mut redacted_fields = Vec::new();
+
+        for rule in &policy.rules {
+            let (parent_path, key) = match rule.path.rsplit_once('.') {
+                Some((parent, key)) => (Some(parent), key),
+                None => (None, rule.path.as_str()),
+            };
+            let parent = match parent_path {
+                Some(p) => p.split('.').try_fold(&mut value, |v, k| v.get_mut(k)),
+                None => Some(&mut value),
+            };
+            let Some(serde_json::Value::Object(map)) = parent else {
+                continue;
+            };
+            if !map.contains_key(key) {
+                continue;
+            }
+            match rule.action {
+                RedactionAction::Mask => {
+                    map.insert(key.to_string(), serde_json::Value::from("[REDACTED]"));
+                }
+                RedactionAction::Drop => {
+                    map.remove(key);
+                }
+            }
+            redacted_fields.push(rule.clone());
+        }
+
+        serde_json::to_string_pretty(&serde_json::json!({
+            "record": value,
+            "redaction": {
+                "original_sha256": original_sha256,
+                "redacted_fields": redacted_fields,
+            },
+        }))
+    }
+
     /// Deserialize from JSON
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
@@ -154,6 +320,32 @@ mod tests {
         assert!(!record.record_id.is_empty());
     }
 
+    #[test]
+    fn test_action_type_allowlist() {
+        let parsed: Vec<ActionType> =
+            serde_json::from_str(r#"["TightenEnvelope", "Rollback", "SelfModify"]"#).unwrap();
+        assert_eq!(
+            parsed,
+            [ActionType::TightenEnvelope, ActionType::Rollback, ActionType::Unknown]
+        );
+        assert!(parsed[0].is_permitted());
+        assert!(!parsed[1].is_permitted() && parsed[1].is_reversal());
+        assert!(!parsed[2].is_permitted() && !parsed[2].is_reversal());
+
+        // Records written before action types existed have no verb at all
+        let record = EvolutionAuditRecord::new(
+            "did:bostrom:test".to_string(),
+            EcoCorridorContext::new("test".to_string(), "Test".to_string()),
+            EvidenceBundle::new("ev1".to_string(), 0.9, 0.1),
+            "test_policy".to_string(),
+            "test_decision".to_string(),
+        );
+        let mut legacy = serde_json::to_value(&record).unwrap();
+        legacy.as_object_mut().unwrap().remove("action_type");
+        let legacy: EvolutionAuditRecord = serde_json::from_value(legacy).unwrap();
+        assert_eq!(legacy.action_type, ActionType::Unspecified);
+    }
+
     #[test]
     fn test_monotonicity_check() {
         let corridor = EcoCorridorContext::new("test".to_string(), "Test".to_string());
@@ -174,4 +366,82 @@ mod tests {
         );
         assert!(record.respects_monotonicity());
     }
+
+    #[test]
+    fn test_redacted_export() {
+        let corridor = EcoCorridorContext::new("test".to_string(), "Test".to_string());
+        let mut evidence = EvidenceBundle::new("ev1".to_string(), 0.9, 0.1);
+        evidence.provenance = Some(
+            [("lab".to_string(), "Phoenix cohort A".to_string())]
+                .into_iter()
+                .collect(),
+        );
+        let record = EvolutionAuditRecord::new(
+            "did:bostrom:test".to_string(),
+            corridor,
+            evidence,
+            "test_policy".to_string(),
+            "test_decision".to_string(),
+        );
+
+        let exported = record
+            .to_redacted_json(&RedactionPolicy::external_auditor().mask("no_such_field"))
+            .unwrap();
+        let v: serde_json::Value = serde_json::from_str(&exported).unwrap();
+
+        assert_eq!(v["record"]["did"], "[REDACTED]");
+        assert!(v["record"]["evidence_bundle"].get("provenance").is_none());
+        assert_eq!(v["record"]["record_id"], record.record_id.as_str());
+        assert_eq!(v["redaction"]["original_sha256"], record.content_hash().unwrap().as_str());
+        assert_eq!(v["redaction"]["redacted_fields"].as_array().unwrap().len(), 2);
+        assert!(!exported.contains("Phoenix cohort A"));
+    }
+
+    #[test]
+    fn test_guard_decision_json_shape_is_stable() {
+        use crate::types::guards::{ForbidReason, GuardDecision};
+
+        let corridor = EcoCorridorContext::new("test".to_string(), "Test".to_string());
+        let evidence = EvidenceBundle::new("ev1".to_string(), 0.9, 0.1);
+        let mut record = EvolutionAuditRecord::new(
+            "did:bostrom:test".to_string(),
+            corridor,
+            evidence,
+            "test_policy".to_string(),
+            "test_decision".to_string(),
+        );
+        record.guard_decisions = vec![
+            GuardDecisionRecord::new("bci", &GuardDecision::AllowFull),
+            GuardDecisionRecord::new(
+                "envelope",
+                &GuardDecision::DegradePrecision("near limit".to_string()),
+            ),
+            GuardDecisionRecord::new(
+                "roh",
+                &GuardDecision::Forbid(
+                    ForbidReason::new("RoH rose").with_evidence_ref(Some("0x_autos".to_string())),
+                ),
+            ),
+        ];
+
+        let value = serde_json::to_value(&record).unwrap();
+        assert_eq!(
+            value["guard_decisions"],
+            serde_json::json!([
+                { "guard": "bci", "verdict": "allow", "detail": null },
+                {
+                    "guard": "envelope",
+                    "verdict": "degrade_precision",
+                    "detail": { "reason": "near limit", "evidence_ref": null }
+                },
+                {
+                    "guard": "roh",
+                    "verdict": "forbid",
+                    "detail": { "reason": "RoH rose", "evidence_ref": "0x_autos" }
+                }
+            ])
+        );
+        let parsed = EvolutionAuditRecord::from_json(&record.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.guard_decisions, record.guard_decisions);
+    }
 }
@@ -4,10 +4,16 @@
 //! integrating biophysical, ecological, and neurorights constraints.
 
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
 /// Unique identifier for a corridor (typically UUID or semantic)
 pub type CorridorId = String;
 
+/// Hard minimum for `corridor_safety`, checked on its own so that low impact
+/// scores elsewhere can never mask an unsafe corridor; also the admissibility
+/// threshold in [`EcoImpactMetrics::is_admissible`]
+pub const CORRIDOR_SAFETY_FLOOR: f64 = 0.7;
+
 /// Ecological impact metrics (0.0–1.0 scale, where 0 = no impact, 1 = maximal)
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct EcoImpactMetrics {
@@ -29,7 +35,7 @@ impl EcoImpactMetrics {
         self.climate_impact <= 0.3
             && self.biodiversity_impact <= 0.3
             && self.biosphere_fragility <= 0.25
-            && self.corridor_safety >= 0.7
+            && self.corridor_safety >= CORRIDOR_SAFETY_FLOOR
             && self.service_impact <= 0.25
     }
 
@@ -101,12 +107,29 @@ impl EcoCorridorContext {
         if self.corridor_name.is_empty() {
             return Err("Corridor name cannot be empty".to_string());
         }
+        // A decision with no jurisdictional grounding cannot be governed
         if self.jurisdictions.is_empty() {
-            return Err("At least one jurisdiction must be specified".to_string());
+            return Err(format!(
+                "Corridor {} has no jurisdictions; at least one must be specified",
+                self.corridor_id
+            ));
+        }
+        if self.jurisdictions.iter().any(|j| j.trim().is_empty()) {
+            return Err(format!(
+                "Corridor {} lists a blank jurisdiction",
+                self.corridor_id
+            ));
         }
         if self.fpic_ids_status == FpicIdsStatus::Revoked {
             return Err("Cannot operate in corridors with revoked FPIC/IDS".to_string());
         }
+        let safety = self.eco_impact.corridor_safety;
+        if safety.is_nan() || safety < CORRIDOR_SAFETY_FLOOR {
+            return Err(format!(
+                "Corridor {} is unsafe: corridor_safety {:.2} is below the hard floor {:.2}",
+                self.corridor_id, safety, CORRIDOR_SAFETY_FLOOR
+            ));
+        }
         if !self.eco_impact.is_admissible() {
             return Err("Ecological impact exceeds admissible thresholds".to_string());
         }
@@ -118,6 +141,131 @@ impl EcoCorridorContext {
         self.fpic_ids_status != FpicIdsStatus::Revoked
             && self.eco_impact.is_admissible()
             && !self.jurisdictions.is_empty()
+            && self.jurisdictions.iter().all(|j| !j.trim().is_empty())
+    }
+}
+
+/// Limits for cumulative eco-impact across successive proposals on one corridor
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImpactTrendConfig {
+    /// Number of most recent proposals considered
+    pub window: usize,
+    /// Maximum sum of `composite_risk` over the window
+    pub max_rolling_risk: f64,
+    /// Maximum least-squares slope of `composite_risk` per proposal
+    pub max_trend: f64,
+}
+
+impl Default for ImpactTrendConfig {
+    fn default() -> Self {
+        Self {
+            window: 10,
+            max_rolling_risk: 1.5,
+            max_trend: 0.02,
+        }
+    }
+}
+
+/// Why the tracker flagged a corridor
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ImpactAlert {
+    /// Cumulative risk over the window is above `max_rolling_risk`
+    RollingRiskExceeded {
+        /// Sum of composite risk over the window
+        sum: f64,
+        /// Configured limit
+        limit: f64,
+    },
+    /// Risk is climbing faster than `max_trend` per proposal
+    RisingTrend {
+        /// Least-squares slope over the window
+        slope: f64,
+        /// Configured limit
+        limit: f64,
+    },
+}
+
+/// Accumulates eco-impact per corridor to catch gradual degradation that
+/// passes single-proposal validation every time
+#[derive(Clone, Debug, Default)]
+pub struct CorridorImpactTracker {
+    /// Window and thresholds
+    pub config: ImpactTrendConfig,
+    history: HashMap<CorridorId, VecDeque<f64>>,
+}
+
+impl CorridorImpactTracker {
+    /// Create a tracker with the given thresholds
+    pub fn new(config: ImpactTrendConfig) -> Self {
+        Self {
+            config,
+            history: HashMap::new(),
+        }
+    }
+
+    /// Record a proposal's metrics for `corridor_id` and return any alerts
+    /// raised by the updated window
+    pub fn record(&mut self, corridor_id: &str, metrics: &EcoImpactMetrics) -> Vec<ImpactAlert> {
+        let window = self.config.window.max(1);
+        let risks = self.history.entry(corridor_id.to_string()).or_default();
+        risks.push_back(metrics.composite_risk());
+        while risks.len() > window {
+            risks.pop_front();
+        }
+        self.alerts(corridor_id)
+    }
+
+    /// Record the current metrics of `corridor`
+    pub fn record_context(&mut self, corridor: &EcoCorridorContext) -> Vec<ImpactAlert> {
+        self.record(&corridor.corridor_id, &corridor.eco_impact)
+    }
+
+    /// Sum of composite risk over the window (0.0 for an unknown corridor)
+    pub fn rolling_risk(&self, corridor_id: &str) -> f64 {
+        self.history
+            .get(corridor_id)
+            .map(|r| r.iter().sum())
+            .unwrap_or(0.0)
+    }
+
+    /// Least-squares slope of composite risk per proposal, once the window
+    /// holds at least two proposals
+    pub fn trend(&self, corridor_id: &str) -> Option<f64> {
+        let risks = self.history.get(corridor_id)?;
+        let n = risks.len();
+        if n < 2 {
+            return None;
+        }
+        let x_mean = (n - 1) as f64 / 2.0;
+        let y_mean = risks.iter().sum::<f64>() / n as f64;
+        let (mut cov, mut var) = (0.0, 0.0);
+        for (i, y) in risks.iter().enumerate() {
+            let dx = i as f64 - x_mean;
+            cov += dx * (y - y_mean);
+            var += dx * dx;
+        }
+        Some(cov / var)
+    }
+
+    /// Current alerts for `corridor_id`
+    pub fn alerts(&self, corridor_id: &str) -> Vec<ImpactAlert> {
+        let mut alerts = Vec::new();
+        let sum = self.rolling_risk(corridor_id);
+        if sum > self.config.max_rolling_risk {
+            alerts.push(ImpactAlert::RollingRiskExceeded {
+                sum,
+                limit: self.config.max_rolling_risk,
+            });
+        }
+        if let Some(slope) = self.trend(corridor_id) {
+            if slope > self.config.max_trend {
+                alerts.push(ImpactAlert::RisingTrend {
+                    slope,
+                    limit: self.config.max_trend,
+                });
+            }
+        }
+        alerts
     }
 }
 
@@ -147,8 +295,78 @@ mod tests {
     fn test_corridor_validation() {
         let mut corridor =
             EcoCorridorContext::new("phx_001".to_string(), "Phoenix Medical".to_string());
+        corridor.eco_impact.corridor_safety = 0.9;
         assert!(corridor.validate().is_err()); // no jurisdictions
         corridor.jurisdictions.push("US/Arizona".to_string());
         assert!(corridor.validate().is_ok());
     }
+
+    #[test]
+    fn test_blank_or_missing_jurisdictions_rejected() {
+        let mut corridor =
+            EcoCorridorContext::new("phx_001".to_string(), "Phoenix Medical".to_string());
+        corridor.eco_impact.corridor_safety = 0.9;
+        let err = corridor.validate().unwrap_err();
+        assert!(err.contains("no jurisdictions"), "{}", err);
+        assert!(!corridor.is_operational());
+
+        corridor.jurisdictions.push("  ".to_string());
+        assert!(corridor.validate().unwrap_err().contains("blank jurisdiction"));
+        assert!(!corridor.is_operational());
+    }
+
+    #[test]
+    fn test_corridor_safety_floor() {
+        let mut corridor =
+            EcoCorridorContext::new("phx_001".to_string(), "Phoenix Medical".to_string());
+        corridor.jurisdictions.push("US/Arizona".to_string());
+        // Every other metric is ideal, so only the floor can reject it
+        corridor.eco_impact.corridor_safety = 0.3;
+        assert!(corridor.eco_impact.composite_risk() < 0.2);
+        let err = corridor.validate().unwrap_err();
+        assert!(err.contains("hard floor"), "{}", err);
+
+        // Anything below the floor gets the dedicated error, not the generic one
+        corridor.eco_impact.corridor_safety = CORRIDOR_SAFETY_FLOOR - 0.01;
+        assert!(corridor.validate().unwrap_err().contains("hard floor"));
+
+        corridor.eco_impact.corridor_safety = CORRIDOR_SAFETY_FLOOR;
+        assert!(corridor.validate().is_ok());
+        corridor.eco_impact.corridor_safety = 0.85;
+        assert!(corridor.validate().is_ok());
+    }
+
+    #[test]
+    fn test_impact_tracker_flags_creeping_risk() {
+        let metrics = |climate: f64| EcoImpactMetrics {
+            climate_impact: climate,
+            biodiversity_impact: 0.1,
+            biosphere_fragility: 0.1,
+            corridor_safety: 0.9,
+            service_impact: 0.1,
+        };
+        let mut tracker = CorridorImpactTracker::new(ImpactTrendConfig {
+            window: 5,
+            max_rolling_risk: 0.6,
+            max_trend: 0.005,
+        });
+
+        // Flat, admissible impact never alerts
+        for _ in 0..8 {
+            assert!(tracker.record("steady", &metrics(0.1)).is_empty());
+        }
+
+        // Each step is individually admissible, but risk keeps climbing
+        let mut last = Vec::new();
+        for i in 0..5 {
+            let m = metrics(0.05 + 0.05 * i as f64);
+            assert!(m.is_admissible());
+            last = tracker.record("creeping", &m);
+        }
+        assert!(last
+            .iter()
+            .any(|a| matches!(a, ImpactAlert::RisingTrend { .. })));
+        assert!(tracker.trend("creeping").unwrap() > 0.0);
+        assert_eq!(tracker.trend("unknown"), None);
+    }
 }
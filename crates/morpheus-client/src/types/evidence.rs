@@ -5,7 +5,7 @@
 //! change in published biophysical evidence.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// A single hex-stamped evidence tag with citation and domain info
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -20,6 +20,50 @@ pub struct EvidenceTag {
     pub citation: String,
     /// Version of the tag schema (e.g., "1.0")
     pub version: String,
+    /// True once newer evidence supersedes this tag version
+    #[serde(default)]
+    pub deprecated: bool,
+    /// Hex ID or domain of the tag that replaces this one, if known
+    #[serde(default)]
+    pub superseded_by: Option<String>,
+}
+
+impl EvidenceTag {
+    /// Mark this tag version as deprecated, optionally naming its successor
+    pub fn deprecate(mut self, superseded_by: Option<String>) -> Self {
+        self.deprecated = true;
+        self.superseded_by = superseded_by;
+        self
+    }
+}
+
+/// How an evidence bundle changed between two versions
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EvidenceDiff {
+    /// Tags present only in the newer bundle
+    pub added_tags: Vec<EvidenceTag>,
+    /// Tags present only in the older bundle
+    pub removed_tags: Vec<EvidenceTag>,
+    /// Newer knowledge factor minus older
+    pub kf_delta: f64,
+    /// Newer uncertainty minus older
+    pub uncertainty_delta: f64,
+}
+
+impl EvidenceDiff {
+    /// True if the bundles carry the same tags and parameters
+    pub fn is_empty(&self) -> bool {
+        self.added_tags.is_empty()
+            && self.removed_tags.is_empty()
+            && self.kf_delta == 0.0
+            && self.uncertainty_delta == 0.0
+    }
+
+    /// True if evidence was dropped, confidence fell, or uncertainty grew;
+    /// such updates should go back through approval
+    pub fn is_weakening(&self) -> bool {
+        !self.removed_tags.is_empty() || self.kf_delta < 0.0 || self.uncertainty_delta > 0.0
+    }
 }
 
 /// A complete evidence bundle: collection of hex-stamped tags backing a constraint
@@ -40,6 +84,11 @@ pub struct EvidenceBundle {
 }
 
 impl EvidenceBundle {
+    /// Start building a bundle with the given ID
+    pub fn builder(id: impl Into<String>) -> EvidenceBundleBuilder {
+        EvidenceBundleBuilder::new(id)
+    }
+
     /// Create a new evidence bundle
     pub fn new(id: String, knowledge_factor: f64, uncertainty: f64) -> Self {
         Self {
@@ -52,13 +101,41 @@ impl EvidenceBundle {
         }
     }
 
+    /// Create a new evidence bundle, rejecting out-of-range parameters
+    ///
+    /// Unlike [`EvidenceBundle::new`], which clamps into `[0.0, 1.0]`, this
+    /// surfaces a NaN or out-of-range input as an error, since it usually
+    /// means an upstream pipeline bug.
+    pub fn try_new(id: String, knowledge_factor: f64, uncertainty: f64) -> Result<Self, String> {
+        if !(0.0..=1.0).contains(&knowledge_factor) {
+            return Err(format!(
+                "Knowledge factor must be in [0.0, 1.0], got {}",
+                knowledge_factor
+            ));
+        }
+        if !(0.0..=1.0).contains(&uncertainty) {
+            return Err(format!("Uncertainty must be in [0.0, 1.0], got {}", uncertainty));
+        }
+        Ok(Self::new(id, knowledge_factor, uncertainty))
+    }
+
     /// Add an evidence tag to the bundle
-    pub fn add_tag(&mut self, tag: EvidenceTag) {
+    ///
+    /// Returns `false` and leaves the bundle unchanged if a tag with the same
+    /// `hex_id` is already present, so repeats can't inflate the tag count.
+    pub fn add_tag(&mut self, tag: EvidenceTag) -> bool {
+        if self.tags.iter().any(|t| t.hex_id == tag.hex_id) {
+            return false;
+        }
         self.tags.push(tag);
+        true
     }
 
     /// Validate the bundle has required structure
-    pub fn validate(&self) -> Result<(), String> {
+    ///
+    /// On success, returns one warning per deprecated tag; logging them is
+    /// left to the caller
+    pub fn validate(&self) -> Result<Vec<String>, String> {
         if self.id.is_empty() {
             return Err("Bundle ID cannot be empty".to_string());
         }
@@ -68,13 +145,61 @@ impl EvidenceBundle {
         if self.tags.len() > 20 {
             return Err("Evidence bundle cannot exceed 20 tags".to_string());
         }
+        // Bundles built by deserialization bypass `add_tag`'s dedup
+        let mut seen = HashSet::new();
+        if let Some(dup) = self.tags.iter().find(|t| !seen.insert(t.hex_id.as_str())) {
+            return Err(format!("Duplicate evidence tag {}", dup.hex_id));
+        }
         if self.knowledge_factor < 0.0 || self.knowledge_factor > 1.0 {
             return Err("Knowledge factor must be in [0.0, 1.0]".to_string());
         }
         if self.uncertainty < 0.0 || self.uncertainty > 1.0 {
             return Err("Uncertainty must be in [0.0, 1.0]".to_string());
         }
-        Ok(())
+        Ok(self
+            .deprecated_tags()
+            .into_iter()
+            .map(|tag| {
+                format!(
+                    "Evidence bundle {} uses deprecated tag {} ({} v{}){}",
+                    self.id,
+                    tag.hex_id,
+                    tag.domain,
+                    tag.version,
+                    tag.superseded_by
+                        .as_ref()
+                        .map(|s| format!("; superseded by {}", s))
+                        .unwrap_or_default()
+                )
+            })
+            .collect())
+    }
+
+    /// Changes from this bundle to `other`, matching tags by `hex_id`
+    pub fn diff(&self, other: &EvidenceBundle) -> EvidenceDiff {
+        let ours: HashSet<&str> = self.tags.iter().map(|t| t.hex_id.as_str()).collect();
+        let theirs: HashSet<&str> = other.tags.iter().map(|t| t.hex_id.as_str()).collect();
+        EvidenceDiff {
+            added_tags: other
+                .tags
+                .iter()
+                .filter(|t| !ours.contains(t.hex_id.as_str()))
+                .cloned()
+                .collect(),
+            removed_tags: self
+                .tags
+                .iter()
+                .filter(|t| !theirs.contains(t.hex_id.as_str()))
+                .cloned()
+                .collect(),
+            kf_delta: other.knowledge_factor - self.knowledge_factor,
+            uncertainty_delta: other.uncertainty - self.uncertainty,
+        }
+    }
+
+    /// Tags in this bundle whose version has been deprecated
+    pub fn deprecated_tags(&self) -> Vec<&EvidenceTag> {
+        self.tags.iter().filter(|t| t.deprecated).collect()
     }
 
     /// Compute an effective safety margin based on knowledge factor and uncertainty
@@ -83,6 +208,69 @@ impl EvidenceBundle {
     }
 }
 
+/// Fluent builder for [`EvidenceBundle`] that validates at `build` time
+#[derive(Clone, Debug, Default)]
+pub struct EvidenceBundleBuilder {
+    id: String,
+    tags: Vec<EvidenceTag>,
+    knowledge_factor: Option<f64>,
+    uncertainty: Option<f64>,
+    provenance: Option<HashMap<String, String>>,
+}
+
+impl EvidenceBundleBuilder {
+    /// Start a builder for a bundle with the given ID
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Add an evidence tag; repeats of the same `hex_id` are dropped at build time
+    pub fn tag(mut self, tag: EvidenceTag) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Set the knowledge factor (required, must be in `[0.0, 1.0]`)
+    pub fn knowledge_factor(mut self, knowledge_factor: f64) -> Self {
+        self.knowledge_factor = Some(knowledge_factor);
+        self
+    }
+
+    /// Set the uncertainty (required, must be in `[0.0, 1.0]`)
+    pub fn uncertainty(mut self, uncertainty: f64) -> Self {
+        self.uncertainty = Some(uncertainty);
+        self
+    }
+
+    /// Record a provenance entry, replacing any earlier value for `key`
+    pub fn provenance(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.provenance
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+        self
+    }
+
+    /// Build the bundle, deduplicating tags and running [`EvidenceBundle::validate`]
+    pub fn build(self) -> Result<EvidenceBundle, String> {
+        let knowledge_factor = self
+            .knowledge_factor
+            .ok_or_else(|| "Knowledge factor must be set".to_string())?;
+        let uncertainty = self
+            .uncertainty
+            .ok_or_else(|| "Uncertainty must be set".to_string())?;
+        let mut bundle = EvidenceBundle::try_new(self.id, knowledge_factor, uncertainty)?;
+        for tag in self.tags {
+            bundle.add_tag(tag);
+        }
+        bundle.provenance = self.provenance;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+}
+
 /// Default biophysical evidence domains (extensible)
 pub struct BiophysicalDomains;
 
@@ -95,6 +283,8 @@ impl BiophysicalDomains {
             description: "ATP consumption and mitochondrial coupling efficiency".to_string(),
             citation: "doi:10.1038/nrn3711".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -106,6 +296,8 @@ impl BiophysicalDomains {
             description: "Localized cortical temperature rise under stimulation".to_string(),
             citation: "doi:10.1016/j.neuroimage.2017.11.014".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -117,6 +309,8 @@ impl BiophysicalDomains {
             description: "Signal stability and artifact rates at BCI electrode interface".to_string(),
             citation: "doi:10.1109/TNSRE.2022.3141234".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -128,6 +322,8 @@ impl BiophysicalDomains {
             description: "Electromagnetic field saturation limits for neural safety".to_string(),
             citation: "doi:10.1109/TBME.2020.3001589".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -139,6 +335,8 @@ impl BiophysicalDomains {
             description: "HRV, LF/HF ratio, and sympathetic/parasympathetic balance".to_string(),
             citation: "doi:10.1016/j.jelectrocard.2015.08.008".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -150,6 +348,8 @@ impl BiophysicalDomains {
             description: "IL-6, TNF-α, CRP, and BDNF levels under neural load".to_string(),
             citation: "doi:10.1038/s41577-021-00566-3".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -161,6 +361,8 @@ impl BiophysicalDomains {
             description: "Internal body state awareness and cognitive load integration".to_string(),
             citation: "doi:10.1038/s41583-021-00440-0".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 
@@ -172,6 +374,8 @@ impl BiophysicalDomains {
             description: "Ecological footprint and corridor biodiversity metrics".to_string(),
             citation: "doi:10.1038/s41467-021-22649-4".to_string(),
             version: "1.0".to_string(),
+            deprecated: false,
+            superseded_by: None,
         }
     }
 }
@@ -187,6 +391,16 @@ mod tests {
         assert_eq!(bundle.uncertainty, 0.05);
     }
 
+    #[test]
+    fn test_try_new_rejects_out_of_range() {
+        assert!(EvidenceBundle::try_new("ok".to_string(), 1.0, 0.0).is_ok());
+        assert!(EvidenceBundle::try_new("kf".to_string(), 1.5, 0.1).is_err());
+        assert!(EvidenceBundle::try_new("unc".to_string(), 0.9, -0.1).is_err());
+        assert!(EvidenceBundle::try_new("nan".to_string(), f64::NAN, 0.1).is_err());
+        // `new` keeps clamping
+        assert_eq!(EvidenceBundle::new("clamp".to_string(), 1.5, 0.1).knowledge_factor, 1.0);
+    }
+
     #[test]
     fn test_evidence_bundle_validation() {
         let mut bundle = EvidenceBundle::new("test".to_string(), 0.9, 0.1);
@@ -195,6 +409,85 @@ mod tests {
         assert!(bundle.validate().is_ok());
     }
 
+    #[test]
+    fn test_duplicate_tags_are_not_counted_twice() {
+        let mut bundle = EvidenceBundle::new("test".to_string(), 0.9, 0.1);
+        assert!(bundle.add_tag(BiophysicalDomains::atp()));
+        assert!(!bundle.add_tag(BiophysicalDomains::atp()));
+        assert_eq!(bundle.tags.len(), 1);
+
+        bundle.tags.push(BiophysicalDomains::atp());
+        assert!(bundle.validate().unwrap_err().contains("Duplicate"));
+    }
+
+    #[test]
+    fn test_deprecated_tag_still_validates() {
+        let mut bundle = EvidenceBundle::new("test".to_string(), 0.9, 0.1);
+        bundle.add_tag(BiophysicalDomains::atp().deprecate(Some("bio.atp.v2".to_string())));
+        bundle.add_tag(BiophysicalDomains::thermal());
+        let warnings = bundle.validate().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("superseded by bio.atp.v2"), "{}", warnings[0]);
+        assert_eq!(bundle.deprecated_tags().len(), 1);
+        assert_eq!(bundle.deprecated_tags()[0].domain, "bio.atp.v1");
+    }
+
+    #[test]
+    fn test_bundle_diff() {
+        let mut before = EvidenceBundle::new("v1".to_string(), 0.9, 0.1);
+        before.add_tag(BiophysicalDomains::atp());
+        before.add_tag(BiophysicalDomains::thermal());
+        assert!(before.diff(&before).is_empty());
+
+        let mut after = EvidenceBundle::new("v2".to_string(), 0.8, 0.15);
+        after.add_tag(BiophysicalDomains::thermal());
+        after.add_tag(BiophysicalDomains::autonomic());
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.added_tags, vec![BiophysicalDomains::autonomic()]);
+        assert_eq!(diff.removed_tags, vec![BiophysicalDomains::atp()]);
+        assert!((diff.kf_delta + 0.1).abs() < 1e-9);
+        assert!((diff.uncertainty_delta - 0.05).abs() < 1e-9);
+        assert!(diff.is_weakening());
+
+        let mut stronger = after.clone();
+        stronger.knowledge_factor = 0.95;
+        stronger.uncertainty = 0.05;
+        assert!(!after.diff(&stronger).is_weakening());
+    }
+
+    #[test]
+    fn test_builder_dedups_and_validates() {
+        let bundle = EvidenceBundle::builder("built")
+            .tag(BiophysicalDomains::atp())
+            .tag(BiophysicalDomains::thermal())
+            .tag(BiophysicalDomains::atp())
+            .knowledge_factor(0.9)
+            .uncertainty(0.1)
+            .provenance("lab", "phoenix-1")
+            .build()
+            .unwrap();
+        assert_eq!(bundle.tags.len(), 2);
+        assert_eq!(bundle.provenance.unwrap()["lab"], "phoenix-1");
+
+        let untagged = EvidenceBundleBuilder::new("empty")
+            .knowledge_factor(0.9)
+            .uncertainty(0.1)
+            .build();
+        assert!(untagged.unwrap_err().contains("at least one tag"));
+        let out_of_range = EvidenceBundle::builder("kf")
+            .tag(BiophysicalDomains::atp())
+            .knowledge_factor(1.5)
+            .uncertainty(0.1)
+            .build();
+        assert!(out_of_range.is_err());
+        let missing = EvidenceBundle::builder("unc")
+            .tag(BiophysicalDomains::atp())
+            .knowledge_factor(0.9)
+            .build();
+        assert!(missing.unwrap_err().contains("Uncertainty"));
+    }
+
     #[test]
     fn test_effective_margin() {
         let bundle = EvidenceBundle::new("test".to_string(), 0.9, 0.1);
@@ -3,7 +3,84 @@
 //! Non-bypassable checks that evaluate whether a proposed evolution respects
 //! biophysical limits and neurorights constraints before any actuation.
 
+use crate::types::evidence::{BiophysicalDomains, EvidenceTag};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Why a guard forbade an action, and the evidence behind the limit
+///
+/// Also deserializes from a bare string, the form `GuardDecision::Forbid`
+/// was stored in before evidence references were added.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(from = "StoredForbidReason")]
+pub struct ForbidReason {
+    /// Numeric/human reason for the rejection
+    pub reason: String,
+    /// Evidence tag `hex_id` (or citation) justifying the limit, if known
+    #[serde(default)]
+    pub evidence_ref: Option<String>,
+}
+
+/// Accepted stored forms of [`ForbidReason`]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredForbidReason {
+    Legacy(String),
+    Current {
+        reason: String,
+        #[serde(default)]
+        evidence_ref: Option<String>,
+    },
+}
+
+impl From<StoredForbidReason> for ForbidReason {
+    fn from(stored: StoredForbidReason) -> Self {
+        match stored {
+            StoredForbidReason::Legacy(reason) => Self::new(reason),
+            StoredForbidReason::Current {
+                reason,
+                evidence_ref,
+            } => Self::new(reason).with_evidence_ref(evidence_ref),
+        }
+    }
+}
+
+impl ForbidReason {
+    /// A reason with no evidence reference
+    pub fn new(reason: impl Into<String>) -> Self {
+        Self {
+            reason: reason.into(),
+            evidence_ref: None,
+        }
+    }
+
+    /// Attach an evidence reference
+    pub fn with_evidence_ref(mut self, evidence_ref: Option<String>) -> Self {
+        self.evidence_ref = evidence_ref;
+        self
+    }
+}
+
+impl fmt::Display for ForbidReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.evidence_ref {
+            Some(evidence) => write!(f, "{} [evidence {}]", self.reason, evidence),
+            None => write!(f, "{}", self.reason),
+        }
+    }
+}
+
+impl From<String> for ForbidReason {
+    fn from(reason: String) -> Self {
+        Self::new(reason)
+    }
+}
+
+impl From<&str> for ForbidReason {
+    fn from(reason: &str) -> Self {
+        Self::new(reason)
+    }
+}
 
 /// Guard decision outcome
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -15,7 +92,117 @@ pub enum GuardDecision {
     /// Pause and wait for better conditions
     PauseAndRest(String),
     /// Forbid the action entirely
-    Forbid(String),
+    Forbid(ForbidReason),
+}
+
+/// One guard's decision in a stable JSON shape for audit analytics
+///
+/// The field names and `verdict` strings are part of the audit record
+/// format; rename nothing here without versioning the record.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GuardDecisionRecord {
+    /// Guard label, as used in metrics ("bci", "roh", "envelope")
+    pub guard: String,
+    /// One of "allow", "degrade_precision", "pause_and_rest", "forbid"
+    pub verdict: String,
+    /// Why the guard did not allow in full; `None` for "allow"
+    pub detail: Option<ForbidReason>,
+}
+
+impl GuardDecisionRecord {
+    /// Record `decision` as made by `guard`
+    pub fn new(guard: impl Into<String>, decision: &GuardDecision) -> Self {
+        let detail = match decision {
+            GuardDecision::AllowFull => None,
+            GuardDecision::DegradePrecision(reason) | GuardDecision::PauseAndRest(reason) => {
+                Some(ForbidReason::new(reason.clone()))
+            }
+            GuardDecision::Forbid(forbid) => Some(forbid.clone()),
+        };
+        Self {
+            guard: guard.into(),
+            verdict: decision.verdict().to_string(),
+            detail,
+        }
+    }
+}
+
+/// Process exit codes per guard severity, for tools embedding the guards
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExitCodePolicy {
+    /// Exit code for DegradePrecision/PauseAndRest (0 = treat warnings as pass)
+    pub warn_code: i32,
+    /// Exit code for Forbid (must be nonzero)
+    pub forbid_code: i32,
+}
+
+impl Default for ExitCodePolicy {
+    fn default() -> Self {
+        Self {
+            warn_code: 0,
+            forbid_code: 1,
+        }
+    }
+}
+
+impl GuardDecision {
+    /// A `Forbid` with no evidence reference
+    pub fn forbid(reason: impl Into<String>) -> Self {
+        GuardDecision::Forbid(ForbidReason::new(reason))
+    }
+
+    /// Whether this is a caution: allowed, but degraded or paused
+    pub fn is_caution(&self) -> bool {
+        matches!(
+            self,
+            GuardDecision::DegradePrecision(_) | GuardDecision::PauseAndRest(_)
+        )
+    }
+
+    /// Severity rank: 0 = allow, 1 = caution (degrade/pause), 2 = forbid
+    pub fn severity(&self) -> u8 {
+        match self {
+            GuardDecision::AllowFull => 0,
+            GuardDecision::DegradePrecision(_) | GuardDecision::PauseAndRest(_) => 1,
+            GuardDecision::Forbid(_) => 2,
+        }
+    }
+
+    /// Stable snake_case name of the variant, as written to audit records
+    pub fn verdict(&self) -> &'static str {
+        match self {
+            GuardDecision::AllowFull => "allow",
+            GuardDecision::DegradePrecision(_) => "degrade_precision",
+            GuardDecision::PauseAndRest(_) => "pause_and_rest",
+            GuardDecision::Forbid(_) => "forbid",
+        }
+    }
+
+    /// Exit code under the default policy (warnings pass, Forbid → 1)
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code_with(&ExitCodePolicy::default())
+    }
+
+    /// Exit code under a caller-supplied policy
+    pub fn exit_code_with(&self, policy: &ExitCodePolicy) -> i32 {
+        match self.severity() {
+            0 => 0,
+            1 => policy.warn_code,
+            _ => policy.forbid_code,
+        }
+    }
+
+    /// The most severe decision in a set (first one wins on ties)
+    pub fn worst<'a>(
+        decisions: impl IntoIterator<Item = &'a GuardDecision>,
+    ) -> Option<&'a GuardDecision> {
+        decisions
+            .into_iter()
+            .fold(None, |worst: Option<&GuardDecision>, d| match worst {
+                Some(w) if w.severity() >= d.severity() => Some(w),
+                _ => Some(d),
+            })
+    }
 }
 
 /// BCI (Biocompatibility Index) ceiling guard
@@ -25,6 +212,8 @@ pub struct BciCeilingGuard {
     pub ceiling: f64,
     /// Warning band (e.g., 0.25, below which throttling begins)
     pub warn_threshold: f64,
+    /// Evidence behind the ceiling (defaults to the interface-coherence tag)
+    pub evidence_ref: Option<String>,
 }
 
 impl BciCeilingGuard {
@@ -33,16 +222,26 @@ impl BciCeilingGuard {
         Self {
             ceiling: ceiling.clamp(0.0, 1.0),
             warn_threshold: warn_threshold.clamp(0.0, ceiling),
+            evidence_ref: Some(BiophysicalDomains::interface_coherence().hex_id),
         }
     }
 
+    /// Cite a different evidence tag for the ceiling
+    pub fn with_evidence(mut self, tag: &EvidenceTag) -> Self {
+        self.evidence_ref = Some(tag.hex_id.clone());
+        self
+    }
+
     /// Evaluate if a current BCI* value passes the guard
     pub fn evaluate(&self, current_bci: f64) -> GuardDecision {
         if current_bci > self.ceiling {
-            GuardDecision::Forbid(format!(
-                "BCI* {} exceeds ceiling {}",
-                current_bci, self.ceiling
-            ))
+            GuardDecision::Forbid(
+                ForbidReason::new(format!(
+                    "BCI* {} exceeds ceiling {}",
+                    current_bci, self.ceiling
+                ))
+                .with_evidence_ref(self.evidence_ref.clone()),
+            )
         } else if current_bci > self.warn_threshold {
             GuardDecision::DegradePrecision(format!(
                 "BCI* {} in warn band; degrading precision",
@@ -61,6 +260,8 @@ pub struct RoHGuard {
     pub ceiling: f64,
     /// The RoH value before proposed change
     pub roh_before: f64,
+    /// Evidence behind the ceiling (defaults to the autonomic-shift tag)
+    pub evidence_ref: Option<String>,
 }
 
 impl RoHGuard {
@@ -69,18 +270,30 @@ impl RoHGuard {
         Self {
             ceiling: ceiling.clamp(0.0, 1.0),
             roh_before: roh_before.clamp(0.0, 1.0),
+            evidence_ref: Some(BiophysicalDomains::autonomic().hex_id),
         }
     }
 
+    /// Cite a different evidence tag for the ceiling
+    pub fn with_evidence(mut self, tag: &EvidenceTag) -> Self {
+        self.evidence_ref = Some(tag.hex_id.clone());
+        self
+    }
+
     /// Evaluate monotonicity: RoH after must not increase
     pub fn evaluate(&self, roh_after: f64) -> GuardDecision {
+        let forbid = |reason: String| {
+            GuardDecision::Forbid(
+                ForbidReason::new(reason).with_evidence_ref(self.evidence_ref.clone()),
+            )
+        };
         if roh_after > self.ceiling {
-            GuardDecision::Forbid(format!(
+            forbid(format!(
                 "RoH {} would exceed ceiling {}",
                 roh_after, self.ceiling
             ))
         } else if roh_after > self.roh_before {
-            GuardDecision::Forbid(format!(
+            forbid(format!(
                 "RoH monotonicity violated: {} -> {} (increase forbidden)",
                 self.roh_before, roh_after
             ))
@@ -99,6 +312,9 @@ pub struct EnvelopeGuard {
     pub prev_duty_cycle: f64,
     /// Previous max session length (minutes)
     pub prev_session_length: u32,
+    /// Evidence behind the envelope (defaults to the thermal tag, since duty
+    /// cycle and session length bound cortical heating)
+    pub evidence_ref: Option<String>,
 }
 
 impl EnvelopeGuard {
@@ -107,18 +323,33 @@ impl EnvelopeGuard {
         Self {
             prev_duty_cycle,
             prev_session_length,
+            evidence_ref: Some(BiophysicalDomains::thermal().hex_id),
         }
     }
 
-    /// Evaluate that new parameters are tighter (or equal)
+    /// Cite a different evidence tag for the envelope
+    pub fn with_evidence(mut self, tag: &EvidenceTag) -> Self {
+        self.evidence_ref = Some(tag.hex_id.clone());
+        self
+    }
+
+    /// Evaluate that new parameters are tighter (or equal).
+    ///
+    /// Requires `new_duty_cycle <= prev_duty_cycle` and
+    /// `new_session_length <= prev_session_length`; any loosening is `Forbid`.
     pub fn evaluate(&self, new_duty_cycle: f64, new_session_length: u32) -> GuardDecision {
+        let forbid = |reason: String| {
+            GuardDecision::Forbid(
+                ForbidReason::new(reason).with_evidence_ref(self.evidence_ref.clone()),
+            )
+        };
         if new_duty_cycle > self.prev_duty_cycle {
-            GuardDecision::Forbid(format!(
+            forbid(format!(
                 "Duty cycle relaxation forbidden: {} -> {}",
                 self.prev_duty_cycle, new_duty_cycle
             ))
         } else if new_session_length > self.prev_session_length {
-            GuardDecision::Forbid(format!(
+            forbid(format!(
                 "Session length relaxation forbidden: {} -> {}",
                 self.prev_session_length, new_session_length
             ))
@@ -150,10 +381,90 @@ mod tests {
         assert!(matches!(guard.evaluate(0.15), GuardDecision::Forbid(_)));
     }
 
+    #[test]
+    fn test_exit_codes() {
+        let policy = ExitCodePolicy {
+            warn_code: 2,
+            forbid_code: 3,
+        };
+        assert_eq!(GuardDecision::AllowFull.exit_code(), 0);
+        assert_eq!(GuardDecision::PauseAndRest("x".into()).exit_code(), 0);
+        assert_eq!(GuardDecision::forbid("x").exit_code(), 1);
+        assert_eq!(GuardDecision::DegradePrecision("x".into()).exit_code_with(&policy), 2);
+        assert_eq!(GuardDecision::forbid("x").exit_code_with(&policy), 3);
+        assert!(GuardDecision::PauseAndRest("x".into()).is_caution());
+        assert!(!GuardDecision::AllowFull.is_caution() && !GuardDecision::forbid("x").is_caution());
+
+        let decisions = vec![
+            GuardDecision::AllowFull,
+            GuardDecision::DegradePrecision("warn".into()),
+            GuardDecision::AllowFull,
+        ];
+        assert_eq!(
+            GuardDecision::worst(&decisions),
+            Some(&GuardDecision::DegradePrecision("warn".into()))
+        );
+    }
+
     #[test]
     fn test_envelope_guard() {
         let guard = EnvelopeGuard::new(0.5, 60);
         assert_eq!(guard.evaluate(0.4, 45), GuardDecision::AllowFull);
         assert!(matches!(guard.evaluate(0.6, 60), GuardDecision::Forbid(_)));
     }
+
+    #[test]
+    fn test_envelope_guard_forbids_any_loosening() {
+        let guard = EnvelopeGuard::new(0.5, 60);
+        assert_eq!(guard.evaluate(0.5, 60), GuardDecision::AllowFull);
+        // Loosened duty cycle with a tightened session is still forbidden
+        assert!(matches!(guard.evaluate(0.51, 30), GuardDecision::Forbid(_)));
+        // Loosened session length with a tightened duty cycle is still forbidden
+        assert!(matches!(guard.evaluate(0.1, 61), GuardDecision::Forbid(_)));
+    }
+
+    #[test]
+    fn test_legacy_forbid_string_deserializes() {
+        let legacy: GuardDecision =
+            serde_json::from_str(r#"{ "Forbid": "RoH 0.4 exceeds ceiling 0.3" }"#).unwrap();
+        assert_eq!(
+            legacy,
+            GuardDecision::Forbid(ForbidReason::new("RoH 0.4 exceeds ceiling 0.3"))
+        );
+
+        let current = GuardDecision::Forbid(
+            ForbidReason::new("BCI* too high").with_evidence_ref(Some("0x_thrm".to_string())),
+        );
+        let json = serde_json::to_string(&current).unwrap();
+        assert_eq!(
+            json,
+            r#"{"Forbid":{"reason":"BCI* too high","evidence_ref":"0x_thrm"}}"#
+        );
+        assert_eq!(serde_json::from_str::<GuardDecision>(&json).unwrap(), current);
+        assert_eq!(
+            serde_json::from_str::<ForbidReason>(r#"{ "reason": "no ref" }"#).unwrap(),
+            ForbidReason::new("no ref")
+        );
+    }
+
+    #[test]
+    fn test_forbid_carries_evidence_ref() {
+        let thermal = BiophysicalDomains::thermal();
+        match EnvelopeGuard::new(0.5, 60).evaluate(0.6, 60) {
+            GuardDecision::Forbid(r) => {
+                assert_eq!(r.evidence_ref.as_deref(), Some(thermal.hex_id.as_str()));
+                assert!(r.to_string().ends_with("[evidence 0x_thrm]"));
+            }
+            other => panic!("expected Forbid, got {:?}", other),
+        }
+
+        let guard = BciCeilingGuard::new(0.3, 0.25).with_evidence(&thermal);
+        assert_eq!(
+            guard.evaluate(0.35),
+            GuardDecision::Forbid(
+                ForbidReason::new("BCI* 0.35 exceeds ceiling 0.3")
+                    .with_evidence_ref(Some("0x_thrm".to_string()))
+            )
+        );
+    }
 }
@@ -3,8 +3,13 @@
 //! Encodes neurorights, biomechanical constraints, and jurisdiction-specific
 //! rules as JSON/ALN policy schemas that can be swapped at runtime.
 
+use crate::types::evidence::EvidenceBundle;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::sync::Arc;
 
 /// A single neurorights constraint
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -32,6 +37,13 @@ pub struct BiomechPolicy {
     pub max_session_minutes: u32,
     /// Deny if BCI* exceeds this threshold
     pub bci_ceiling: f64,
+    /// Deny if RoH would exceed this threshold (at most the 0.3 constitutional ceiling)
+    #[serde(default = "default_roh_ceiling")]
+    pub roh_ceiling: f64,
+}
+
+fn default_roh_ceiling() -> f64 {
+    ROH_CONSTITUTIONAL_CEILING
 }
 
 /// A complete pluggable policy profile
@@ -49,6 +61,12 @@ pub struct PolicyProfile {
     pub corridor_polytopes: HashMap<String, Vec<f64>>,
     /// Minimum rights floor (non-derogable)
     pub minimum_rights: Vec<String>,
+    /// Evidence domains every proposal must carry (e.g., "bio.thermal.v1")
+    #[serde(default)]
+    pub required_evidence_domains: Vec<String>,
+    /// Reject evidence bundles created more than this many days ago
+    #[serde(default)]
+    pub max_evidence_age_days: Option<u32>,
     /// Authority/source of this profile (e.g., "EU_AI_Act", "Chilean_Neurorights_Amendment")
     pub authority: String,
     /// Effective date (ISO 8601)
@@ -71,6 +89,7 @@ impl PolicyProfile {
                 max_duty_cycle: 0.5,
                 max_session_minutes: 60,
                 bci_ceiling: 0.25,
+                roh_ceiling: ROH_CONSTITUTIONAL_CEILING,
             },
             corridor_polytopes: HashMap::new(),
             minimum_rights: vec![
@@ -79,8 +98,10 @@ impl PolicyProfile {
                 "right_to_identity".to_string(),
                 "right_to_privacy".to_string(),
             ],
+            required_evidence_domains: Vec::new(),
+            max_evidence_age_days: None,
             authority,
-            effective_date: chrono::Utc::now().to_rfc3339(),
+            effective_date: Utc::now().to_rfc3339(),
             notes: None,
         }
     }
@@ -101,12 +122,164 @@ impl PolicyProfile {
         if self.biomech_policy.bci_ceiling < 0.0 || self.biomech_policy.bci_ceiling > 1.0 {
             return Err("BCI ceiling must be in [0.0, 1.0]".to_string());
         }
+        let roh_ceiling = self.biomech_policy.roh_ceiling;
+        if !(roh_ceiling > 0.0 && roh_ceiling <= ROH_CONSTITUTIONAL_CEILING) {
+            return Err(format!(
+                "RoH ceiling must be in (0, {}], got {}",
+                ROH_CONSTITUTIONAL_CEILING, roh_ceiling
+            ));
+        }
         if self.minimum_rights.is_empty() {
             return Err("Minimum rights cannot be empty".to_string());
         }
         Ok(())
     }
 
+    /// Apply an RFC 7386 JSON Merge Patch and re-validate the result
+    ///
+    /// The profile is left unchanged on error. Patches may not name a field
+    /// the profile doesn't have, drop any existing minimum right, or set
+    /// `biomech_policy.roh_ceiling` above the 0.3 constitutional ceiling.
+    pub fn apply_merge_patch(&mut self, patch: &serde_json::Value) -> Result<(), String> {
+        let mut merged = serde_json::to_value(&*self).map_err(|e| e.to_string())?;
+        merge_patch(&mut merged, patch);
+
+        let patched: PolicyProfile = serde_json::from_value(merged.clone())
+            .map_err(|e| format!("Patched profile is malformed: {}", e))?;
+        // Serde ignores unknown keys, so any key that doesn't survive a round
+        // trip through the struct was a typo or a field that doesn't exist.
+        let round_trip = serde_json::to_value(&patched).map_err(|e| e.to_string())?;
+        if let Some(field) = first_unknown_key(&merged, &round_trip, "") {
+            return Err(format!("Patch sets unknown field '{}'", field));
+        }
+        if let Some(dropped) = self
+            .minimum_rights
+            .iter()
+            .find(|r| !patched.minimum_rights.contains(r))
+        {
+            return Err(format!("Patch would remove minimum right '{}'", dropped));
+        }
+        patched.validate()?;
+
+        *self = patched;
+        Ok(())
+    }
+
+    /// Required evidence domains absent from `bundle`, in declaration order
+    pub fn missing_evidence_domains<'a>(&'a self, bundle: &EvidenceBundle) -> Vec<&'a str> {
+        self.required_evidence_domains
+            .iter()
+            .filter(|required| !bundle.tags.iter().any(|t| &t.domain == *required))
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Check that `bundle` is no older than `max_evidence_age_days` at `now`
+    ///
+    /// Always passes when the profile sets no limit. With a limit, a
+    /// `created_at` that is not valid RFC 3339 fails, since its age is unknown.
+    pub fn check_evidence_age(
+        &self,
+        bundle: &EvidenceBundle,
+        now: DateTime<Utc>,
+    ) -> Result<(), String> {
+        let Some(max_days) = self.max_evidence_age_days else {
+            return Ok(());
+        };
+        let created = DateTime::parse_from_rfc3339(&bundle.created_at).map_err(|e| {
+            format!(
+                "Evidence bundle {} has unparseable created_at '{}': {}",
+                bundle.id, bundle.created_at, e
+            )
+        })?;
+        let age_days = (now - created.with_timezone(&Utc)).num_days();
+        if age_days > i64::from(max_days) {
+            return Err(format!(
+                "Evidence bundle {} is {} days old; policy {} allows at most {}",
+                bundle.id, age_days, self.name, max_days
+            ));
+        }
+        Ok(())
+    }
+
+    /// List every way `self` is looser than `other`
+    ///
+    /// Raised ceilings and limits, longer sessions, and enforced constraints,
+    /// minimum rights, or required evidence domains that `self` drops all
+    /// count as relaxations, as does a longer or removed evidence age limit.
+    pub fn is_at_least_as_strict_as(&self, other: &PolicyProfile) -> StrictnessReport {
+        let mut relaxations = Vec::new();
+        let (new, old) = (&self.biomech_policy, &other.biomech_policy);
+
+        for (field, proposed, previous) in [
+            ("biomech_policy.bci_ceiling", new.bci_ceiling, old.bci_ceiling),
+            ("biomech_policy.max_effect_size", new.max_effect_size, old.max_effect_size),
+            ("biomech_policy.max_duty_cycle", new.max_duty_cycle, old.max_duty_cycle),
+            ("biomech_policy.roh_ceiling", new.roh_ceiling, old.roh_ceiling),
+        ] {
+            if proposed > previous {
+                relaxations.push(Relaxation::new(
+                    field,
+                    format!("raised from {} to {}", previous, proposed),
+                ));
+            }
+        }
+        if new.max_session_minutes > old.max_session_minutes {
+            relaxations.push(Relaxation::new(
+                "biomech_policy.max_session_minutes",
+                format!(
+                    "lengthened from {} to {} minutes",
+                    old.max_session_minutes, new.max_session_minutes
+                ),
+            ));
+        }
+
+        for constraint in other.neurorights_constraints.iter().filter(|c| c.enforced) {
+            if !self.is_constraint_enforced(&constraint.name) {
+                relaxations.push(Relaxation::new(
+                    "neurorights_constraints",
+                    format!("'{}' is no longer enforced", constraint.name),
+                ));
+            }
+        }
+        for right in &other.minimum_rights {
+            if !self.minimum_rights.contains(right) {
+                relaxations.push(Relaxation::new(
+                    "minimum_rights",
+                    format!("'{}' was dropped", right),
+                ));
+            }
+        }
+        for domain in &other.required_evidence_domains {
+            if !self.required_evidence_domains.contains(domain) {
+                relaxations.push(Relaxation::new(
+                    "required_evidence_domains",
+                    format!("'{}' is no longer required", domain),
+                ));
+            }
+        }
+        if let Some(old_max) = other.max_evidence_age_days {
+            match self.max_evidence_age_days {
+                None => relaxations.push(Relaxation::new(
+                    "max_evidence_age_days",
+                    format!("limit of {} days was removed", old_max),
+                )),
+                Some(new_max) if new_max > old_max => relaxations.push(Relaxation::new(
+                    "max_evidence_age_days",
+                    format!("raised from {} to {} days", old_max, new_max),
+                )),
+                Some(_) => {}
+            }
+        }
+
+        StrictnessReport { relaxations }
+    }
+
+    /// Freeze this profile so it can be shared but never mutated
+    pub fn freeze(self) -> FrozenPolicyProfile {
+        FrozenPolicyProfile(Arc::new(self))
+    }
+
     /// Check if a constraint is enforced
     pub fn is_constraint_enforced(&self, constraint_name: &str) -> bool {
         self.neurorights_constraints
@@ -117,8 +290,254 @@ impl PolicyProfile {
     }
 }
 
+/// A policy profile that can no longer be mutated
+///
+/// Derefs to [`PolicyProfile`] for reads only; cloning shares the same
+/// `Arc`. To revise governance, copy it out with
+/// [`FrozenPolicyProfile::to_profile`] and freeze the new version.
+#[derive(Clone, Debug)]
+pub struct FrozenPolicyProfile(Arc<PolicyProfile>);
+
+impl FrozenPolicyProfile {
+    /// An owned, editable copy of the frozen profile
+    pub fn to_profile(&self) -> PolicyProfile {
+        (*self.0).clone()
+    }
+}
+
+impl Deref for FrozenPolicyProfile {
+    type Target = PolicyProfile;
+
+    fn deref(&self) -> &PolicyProfile {
+        &self.0
+    }
+}
+
+impl From<PolicyProfile> for FrozenPolicyProfile {
+    fn from(profile: PolicyProfile) -> Self {
+        profile.freeze()
+    }
+}
+
+/// Compare dotted versions segment by segment ("1.10" > "1.9"); numeric
+/// segments compare as numbers, anything else as text, and a missing
+/// segment counts as lower ("1.0" < "1.0.1")
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let mut left = a.split('.');
+    let mut right = b.split('.');
+    loop {
+        match (left.next(), right.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) => {
+                let ord = match (x.parse::<u64>(), y.parse::<u64>()) {
+                    (Ok(x), Ok(y)) => x.cmp(&y),
+                    _ => x.cmp(y),
+                };
+                if ord != Ordering::Equal {
+                    return ord;
+                }
+            }
+        }
+    }
+}
+
+/// Frozen policy profiles keyed by `(name, version)`
+///
+/// Versions of a name only move forward: inserting a version at or below
+/// the latest registered one is rejected unless forced, so a stale profile
+/// can't quietly replace a newer one.
+#[derive(Clone, Debug, Default)]
+pub struct PolicyRegistry {
+    profiles: HashMap<String, Vec<FrozenPolicyProfile>>,
+}
+
+impl PolicyRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a profile; fails if it doesn't validate or if the same or a
+    /// newer version of its name is already registered
+    pub fn insert(&mut self, profile: impl Into<FrozenPolicyProfile>) -> Result<(), String> {
+        let profile = profile.into();
+        profile.validate()?;
+        if let Some(latest) = self.latest(&profile.name) {
+            if compare_versions(&profile.version, &latest.version) != Ordering::Greater {
+                return Err(format!(
+                    "Policy {} v{} is not newer than registered v{}",
+                    profile.name, profile.version, latest.version
+                ));
+            }
+        }
+        self.profiles
+            .entry(profile.name.clone())
+            .or_default()
+            .push(profile);
+        Ok(())
+    }
+
+    /// Register a profile regardless of version order, replacing any profile
+    /// with the same name and version
+    pub fn insert_forced(&mut self, profile: impl Into<FrozenPolicyProfile>) -> Result<(), String> {
+        let profile = profile.into();
+        profile.validate()?;
+        let versions = self.profiles.entry(profile.name.clone()).or_default();
+        versions.retain(|p| p.version != profile.version);
+        versions.push(profile);
+        Ok(())
+    }
+
+    /// The profile registered under exactly `name` and `version`
+    pub fn get(&self, name: &str, version: &str) -> Option<FrozenPolicyProfile> {
+        self.profiles
+            .get(name)?
+            .iter()
+            .find(|p| p.version == version)
+            .cloned()
+    }
+
+    /// The highest registered version of `name`
+    pub fn latest(&self, name: &str) -> Option<FrozenPolicyProfile> {
+        self.profiles
+            .get(name)?
+            .iter()
+            .max_by(|a, b| compare_versions(&a.version, &b.version))
+            .cloned()
+    }
+
+    /// Registered versions of `name`, oldest first
+    pub fn versions(&self, name: &str) -> Vec<String> {
+        let mut versions: Vec<String> = self
+            .profiles
+            .get(name)
+            .map(|ps| ps.iter().map(|p| p.version.clone()).collect())
+            .unwrap_or_default();
+        versions.sort_by(|a, b| compare_versions(a, b));
+        versions
+    }
+}
+
+/// One way a profile is looser than the one it is compared against
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Relaxation {
+    /// Profile field that was loosened (e.g., "biomech_policy.bci_ceiling")
+    pub field: String,
+    /// Human description of the change
+    pub detail: String,
+}
+
+impl Relaxation {
+    fn new(field: &str, detail: String) -> Self {
+        Self {
+            field: field.to_string(),
+            detail,
+        }
+    }
+}
+
+/// Result of `PolicyProfile::is_at_least_as_strict_as`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct StrictnessReport {
+    /// Every relaxation found; empty means no governance downgrade
+    pub relaxations: Vec<Relaxation>,
+}
+
+impl StrictnessReport {
+    /// True when nothing was relaxed
+    pub fn is_at_least_as_strict(&self) -> bool {
+        self.relaxations.is_empty()
+    }
+}
+
+/// Hard RoH ceiling no policy edit may exceed
+const ROH_CONSTITUTIONAL_CEILING: f64 = 0.3;
+
+/// RFC 7386: objects merge recursively, `null` removes a key, anything else replaces
+fn merge_patch(target: &mut serde_json::Value, patch: &serde_json::Value) {
+    let serde_json::Value::Object(patch_map) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = serde_json::Value::Object(serde_json::Map::new());
+    }
+    if let serde_json::Value::Object(target_map) = target {
+        for (key, value) in patch_map {
+            if value.is_null() {
+                target_map.remove(key);
+            } else {
+                merge_patch(
+                    target_map.entry(key.clone()).or_insert(serde_json::Value::Null),
+                    value,
+                );
+            }
+        }
+    }
+}
+
+/// Dotted path of the first key in `merged` that `round_trip` lacks
+fn first_unknown_key(
+    merged: &serde_json::Value,
+    round_trip: &serde_json::Value,
+    path: &str,
+) -> Option<String> {
+    match (merged, round_trip) {
+        (serde_json::Value::Object(m), serde_json::Value::Object(r)) => {
+            m.iter().find_map(|(key, value)| {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match r.get(key) {
+                    Some(kept) => first_unknown_key(value, kept, &field),
+                    None => Some(field),
+                }
+            })
+        }
+        (serde_json::Value::Array(m), serde_json::Value::Array(r)) => m
+            .iter()
+            .zip(r)
+            .enumerate()
+            .find_map(|(i, (value, kept))| first_unknown_key(value, kept, &format!("{}[{}]", path, i))),
+        _ => None,
+    }
+}
+
+/// EU member states, routed to the EU profile by country code
+const EU_MEMBER_CODES: &[&str] = &[
+    "AT", "BE", "BG", "CY", "CZ", "DE", "DK", "EE", "ES", "FI", "FR", "GR", "HR", "HU", "IE",
+    "IT", "LT", "LU", "LV", "MT", "NL", "PL", "PT", "RO", "SE", "SI", "SK",
+];
+
 /// Predefined policy profiles for common jurisdictions
 impl PolicyProfile {
+    /// The built-in profile for a jurisdiction such as "EU", "DE/Bavaria",
+    /// "CL" or "US/Arizona/Phoenix"
+    ///
+    /// Matching is case-insensitive on `/`-separated segments, so a region
+    /// inherits its country's profile. Returns `None` when no built-in
+    /// profile covers the jurisdiction.
+    pub fn for_jurisdiction(jurisdiction: &str) -> Option<PolicyProfile> {
+        let segments: Vec<String> = jurisdiction
+            .split('/')
+            .map(|s| s.trim().to_ascii_uppercase())
+            .collect();
+        let country = segments.first()?.as_str();
+        match country {
+            "EU" => Some(Self::eu_neurorights()),
+            c if EU_MEMBER_CODES.contains(&c) => Some(Self::eu_neurorights()),
+            "CL" | "CHILE" => Some(Self::chile_neurorights()),
+            "US" if segments.get(1).map(String::as_str) == Some("ARIZONA") => {
+                Some(Self::phoenix_medical())
+            }
+            _ => None,
+        }
+    }
+
     /// EU neurorights policy profile (GDPR-aligned)
     pub fn eu_neurorights() -> Self {
         let mut profile = PolicyProfile::new(
@@ -192,6 +611,45 @@ mod tests {
         assert!(profile.is_constraint_enforced("noSubconsciousTargeting"));
     }
 
+    #[test]
+    fn test_for_jurisdiction() {
+        let name = |j: &str| PolicyProfile::for_jurisdiction(j).map(|p| p.name);
+        assert_eq!(name("EU").as_deref(), Some("EU_neurorights"));
+        assert_eq!(name("de/Bavaria").as_deref(), Some("EU_neurorights"));
+        assert_eq!(name("CL").as_deref(), Some("Chile_neurorights"));
+        assert_eq!(name("US/Arizona").as_deref(), Some("Phoenix_medical"));
+        assert_eq!(name("US/Arizona/Phoenix").as_deref(), Some("Phoenix_medical"));
+        assert_eq!(name("US/Texas"), None);
+        assert_eq!(name("  "), None);
+    }
+
+    #[test]
+    fn test_policy_registry_versions() {
+        let versioned = |version: &str, ceiling: f64| {
+            let mut profile = PolicyProfile::eu_neurorights();
+            profile.version = version.to_string();
+            profile.biomech_policy.bci_ceiling = ceiling;
+            profile
+        };
+        let mut registry = PolicyRegistry::new();
+        registry.insert(versioned("1.0", 0.20)).unwrap();
+        registry.insert(versioned("1.10", 0.18)).unwrap();
+        assert!(registry.insert(versioned("1.9", 0.25)).is_err());
+        assert!(registry.insert(versioned("1.10", 0.25)).is_err());
+
+        assert_eq!(registry.latest("EU_neurorights").unwrap().version, "1.10");
+        assert_eq!(
+            registry.get("EU_neurorights", "1.0").unwrap().biomech_policy.bci_ceiling,
+            0.20
+        );
+        assert!(registry.get("EU_neurorights", "2.0").is_none());
+        assert!(registry.latest("Chile_neurorights").is_none());
+
+        registry.insert_forced(versioned("1.9", 0.19)).unwrap();
+        assert_eq!(registry.versions("EU_neurorights"), vec!["1.0", "1.9", "1.10"]);
+        assert_eq!(registry.latest("EU_neurorights").unwrap().version, "1.10");
+    }
+
     #[test]
     fn test_policy_constraint() {
         let mut profile = PolicyProfile::new("test".to_string(), "1.0".to_string(), "test".to_string());
@@ -203,4 +661,108 @@ mod tests {
         profile.add_neurorights_constraint(constraint);
         assert!(profile.is_constraint_enforced("test_constraint"));
     }
+
+    #[test]
+    fn test_merge_patch() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        profile
+            .apply_merge_patch(&serde_json::json!({
+                "biomech_policy": { "bci_ceiling": 0.22 },
+                "notes": "raised after review"
+            }))
+            .unwrap();
+        assert_eq!(profile.biomech_policy.bci_ceiling, 0.22);
+        assert_eq!(profile.biomech_policy.max_session_minutes, 60);
+        assert_eq!(profile.notes.as_deref(), Some("raised after review"));
+
+        profile.apply_merge_patch(&serde_json::json!({ "notes": null })).unwrap();
+        assert!(profile.notes.is_none());
+
+        profile
+            .apply_merge_patch(&serde_json::json!({ "biomech_policy": { "roh_ceiling": 0.25 } }))
+            .unwrap();
+        assert_eq!(profile.biomech_policy.roh_ceiling, 0.25);
+
+        let before = profile.clone();
+        for patch in [
+            serde_json::json!({ "minimum_rights": ["right_to_consent"] }),
+            serde_json::json!({ "biomech_policy": { "roh_ceiling": 0.35 } }),
+            serde_json::json!({ "biomech_policy": { "bci_ceiling": 1.5 } }),
+        ] {
+            assert!(profile.apply_merge_patch(&patch).is_err(), "{}", patch);
+        }
+        assert_eq!(profile.biomech_policy.roh_ceiling, 0.25);
+        assert_eq!(profile.biomech_policy.bci_ceiling, before.biomech_policy.bci_ceiling);
+        assert_eq!(profile.minimum_rights, before.minimum_rights);
+    }
+
+    #[test]
+    fn test_merge_patch_rejects_unknown_fields() {
+        let mut profile = PolicyProfile::eu_neurorights();
+        for (patch, field) in [
+            (serde_json::json!({ "roh_ceiling": 0.2 }), "roh_ceiling"),
+            (
+                serde_json::json!({ "biomech_policy": { "bci_celing": 0.1 } }),
+                "biomech_policy.bci_celing",
+            ),
+            (
+                serde_json::json!({ "neurorights_constraints": [
+                    { "name": "x", "description": "y", "enforced": true, "strict": true }
+                ] }),
+                "neurorights_constraints[0].strict",
+            ),
+        ] {
+            let err = profile.apply_merge_patch(&patch).unwrap_err();
+            assert!(err.contains(field), "{}", err);
+        }
+        assert_eq!(profile.biomech_policy.bci_ceiling, 0.20);
+
+        // Integer values for float fields are not mistaken for unknown keys
+        profile
+            .apply_merge_patch(&serde_json::json!({ "biomech_policy": { "max_effect_size": 0 } }))
+            .unwrap();
+        assert_eq!(profile.biomech_policy.max_effect_size, 0.0);
+    }
+
+    #[test]
+    fn test_strictness_comparison() {
+        let eu = PolicyProfile::eu_neurorights();
+        assert!(eu.is_at_least_as_strict_as(&eu).is_at_least_as_strict());
+
+        let mut tighter = eu.clone();
+        tighter.biomech_policy.bci_ceiling = 0.15;
+        tighter.biomech_policy.max_session_minutes = 45;
+        assert!(tighter.is_at_least_as_strict_as(&eu).is_at_least_as_strict());
+
+        // Chile raises the BCI ceiling and drops both EU constraints
+        let report = PolicyProfile::chile_neurorights().is_at_least_as_strict_as(&eu);
+        let fields: Vec<&str> = report.relaxations.iter().map(|r| r.field.as_str()).collect();
+        assert_eq!(
+            fields,
+            [
+                "biomech_policy.bci_ceiling",
+                "neurorights_constraints",
+                "neurorights_constraints"
+            ]
+        );
+
+        let report = PolicyProfile::phoenix_medical().is_at_least_as_strict_as(&eu);
+        assert!(report
+            .relaxations
+            .iter()
+            .any(|r| r.field == "biomech_policy.max_session_minutes"));
+    }
+
+    #[test]
+    fn test_frozen_profile_shares_and_copies() {
+        let frozen = PolicyProfile::eu_neurorights().freeze();
+        let shared = frozen.clone();
+        assert!(shared.is_constraint_enforced("noSubconsciousTargeting"));
+        assert_eq!(shared.biomech_policy.bci_ceiling, 0.20);
+
+        let mut next = frozen.to_profile();
+        next.biomech_policy.bci_ceiling = 0.15;
+        assert_eq!(frozen.biomech_policy.bci_ceiling, 0.20);
+        assert!(next.freeze().is_at_least_as_strict_as(&frozen).is_at_least_as_strict());
+    }
 }
@@ -0,0 +1,154 @@
+//! End-to-end tests: policy → proposal → reconciliation → audit record → DID signature
+
+use morpheus_client::{
+    bostrom::did_integration::{
+        verify_audit_record, verify_signature, DidKeyPair, SIGNATURE_ALGORITHM,
+    },
+    core::reconciliation::{load_proposals, EvolutionProposal, ReconciliationEngine},
+    types::{
+        audit::{ActionType, EvolutionOutcome},
+        corridor::{EcoCorridorContext, EcoImpactMetrics, FpicIdsStatus},
+        evidence::{BiophysicalDomains, EvidenceBundle},
+        policy::PolicyProfile,
+    },
+    MorpheusError,
+};
+
+fn corridor() -> EcoCorridorContext {
+    let mut corridor = EcoCorridorContext::new(
+        "phoenix_medical_001".to_string(),
+        "Phoenix Medical Corridor".to_string(),
+    );
+    corridor.jurisdictions.push("US/Arizona".to_string());
+    corridor.fpic_ids_status = FpicIdsStatus::Granted;
+    corridor.eco_impact = EcoImpactMetrics {
+        climate_impact: 0.1,
+        biodiversity_impact: 0.05,
+        biosphere_fragility: 0.1,
+        corridor_safety: 0.85,
+        service_impact: 0.08,
+    };
+    corridor
+}
+
+fn evidence() -> EvidenceBundle {
+    let mut evidence = EvidenceBundle::new("ev_001".to_string(), 0.92, 0.08);
+    evidence.add_tag(BiophysicalDomains::atp());
+    evidence.add_tag(BiophysicalDomains::thermal());
+    evidence.add_tag(BiophysicalDomains::autonomic());
+    evidence
+}
+
+fn proposal(did: &str, proposed_bci: f64, proposed_roh: f64) -> EvolutionProposal {
+    EvolutionProposal {
+        did: did.to_string(),
+        corridor_context: corridor(),
+        evidence_bundle: evidence(),
+        neuromorphic_decision: "Tighten somatosensory feedback envelope".to_string(),
+        action_type: ActionType::TightenEnvelope,
+        current_bci: 0.12,
+        proposed_bci,
+        current_roh: 0.10,
+        proposed_roh,
+        current_duty_cycle: 0.40,
+        proposed_duty_cycle: 0.35,
+        current_session_length: 90,
+        proposed_session_length: 75,
+    }
+}
+
+#[test]
+fn approved_proposal_produces_verifiable_signed_record() {
+    let keypair =
+        DidKeyPair::generate("bostrom18sd2ujv24ual9c9pshtxys6j8knh6xaead9ye7".to_string())
+            .unwrap();
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+
+    let (outcome, mut record) = engine
+        .evaluate_evolution(&proposal(&keypair.did.did, 0.10, 0.08))
+        .expect("tightening proposal should be approved");
+    assert_eq!(outcome, EvolutionOutcome::Allowed);
+    assert_eq!(record.policy_profile, "EU_neurorights");
+    assert!(record.respects_monotonicity());
+    record.validate().unwrap();
+
+    // Round-trip through JSON, then sign and verify the exact bytes that were signed
+    let restored =
+        morpheus_client::EvolutionAuditRecord::from_json(&record.to_json().unwrap()).unwrap();
+    assert_eq!(restored.record_id, record.record_id);
+
+    let signature = keypair.sign_audit_record(&mut record).unwrap();
+    assert_eq!(record.signature.as_deref(), Some(signature.as_str()));
+    assert_eq!(record.signature_algorithm.as_deref(), Some(SIGNATURE_ALGORITHM));
+    assert!(verify_audit_record(&keypair.public_key_hex(), &record).unwrap());
+    assert!(!verify_signature(&keypair.public_key_hex(), b"tampered", &signature).unwrap());
+
+    // The algorithm is covered by the signature and must be one we verify
+    record.signature_algorithm = Some("HmacSha256".to_string());
+    assert!(verify_audit_record(&keypair.public_key_hex(), &record).is_err());
+}
+
+#[test]
+fn roh_increase_is_rejected_as_monotonicity_violation() {
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+
+    let err = engine
+        .evaluate_evolution(&proposal("did:bostrom:test", 0.10, 0.14))
+        .unwrap_err();
+    assert!(matches!(err, MorpheusError::MonotonicityViolation(_)), "{err}");
+}
+
+#[test]
+fn bci_above_policy_ceiling_is_rejected_by_guard() {
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+    let mut p = proposal("did:bostrom:test", 0.25, 0.08);
+    p.current_bci = 0.30;
+
+    let err = engine.evaluate_evolution(&p).unwrap_err();
+    assert!(matches!(err, MorpheusError::GuardRejection(_)), "{err}");
+}
+
+#[test]
+fn loosened_duty_cycle_is_rejected_by_envelope_guard() {
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+    let mut p = proposal("did:bostrom:test", 0.10, 0.08);
+    p.proposed_duty_cycle = 0.45;
+
+    let err = engine.evaluate_evolution(&p).unwrap_err();
+    assert!(matches!(err, MorpheusError::GuardRejection(_)), "{err}");
+}
+
+#[test]
+fn loosened_session_length_is_rejected_by_envelope_guard() {
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+    let mut p = proposal("did:bostrom:test", 0.10, 0.08);
+    p.proposed_session_length = 120;
+
+    let err = engine.evaluate_evolution(&p).unwrap_err();
+    assert!(matches!(err, MorpheusError::GuardRejection(_)), "{err}");
+}
+
+#[test]
+fn proposal_batch_round_trips_through_json_and_reports_outcomes() {
+    let engine = ReconciliationEngine::new(PolicyProfile::eu_neurorights()).unwrap();
+    let batch = vec![
+        proposal("did:bostrom:a", 0.10, 0.08),
+        proposal("did:bostrom:b", 0.10, 0.14),
+    ];
+    let path = std::env::temp_dir().join(format!("proposals-{}.json", std::process::id()));
+    std::fs::write(&path, serde_json::to_string(&batch).unwrap()).unwrap();
+    let loaded = load_proposals(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let report = engine.evaluate_batch(&loaded).unwrap();
+    assert_eq!((report.evaluated, report.allowed), (2, 1));
+    assert_eq!(report.entries[0].outcome, EvolutionOutcome::Allowed);
+    assert!(report.entries[0].record_id.is_some());
+    assert!(matches!(report.entries[1].outcome, EvolutionOutcome::Forbidden(_)));
+    assert!(report.entries[1].record_id.is_none());
+
+    assert!(matches!(
+        load_proposals(std::env::temp_dir().join("no-such-proposals.json")),
+        Err(MorpheusError::IoError(_))
+    ));
+}
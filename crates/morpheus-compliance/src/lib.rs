@@ -19,6 +19,22 @@ pub struct ComplianceVerification {
     pub items: Vec<ComplianceClaim>,
 }
 
+/// Outcome of one check run by `ComplianceVerification::verify`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+/// Machine-readable result of `ComplianceVerification::verify`, for CI gates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ComplianceReport {
+    /// True only when every check passed.
+    pub overall_ok: bool,
+    pub checks: Vec<ComplianceCheck>,
+}
+
 #[derive(Debug, Error)]
 pub enum ComplianceError {
     #[error("no such claim: {0}")]
@@ -61,6 +77,37 @@ impl ComplianceVerification {
         }
     }
 
+    /// Check that there is at least one claim and that every claim is
+    /// credible and backed by the registry or by industry best practice.
+    pub fn verify(&self) -> ComplianceReport {
+        let mut checks = vec![ComplianceCheck {
+            name: "claims_present".to_string(),
+            passed: !self.items.is_empty(),
+            detail: format!("{} claim(s) declared", self.items.len()),
+        }];
+        for item in &self.items {
+            let backing = match (item.found_in_registry, item.industry_best_practice) {
+                (true, _) => "found in registry",
+                (false, true) => "not in registry; accepted as industry best practice",
+                (false, false) => "neither in registry nor industry best practice",
+            };
+            let backed = item.found_in_registry || item.industry_best_practice;
+            checks.push(ComplianceCheck {
+                name: item.claim.clone(),
+                passed: item.credible && backed,
+                detail: if item.credible {
+                    backing.to_string()
+                } else {
+                    format!("not credible; {}", backing)
+                },
+            });
+        }
+        ComplianceReport {
+            overall_ok: checks.iter().all(|c| c.passed),
+            checks,
+        }
+    }
+
     pub fn is_credible(&self, claim: &str) -> Result<bool, ComplianceError> {
         self.items
             .iter()
@@ -69,3 +116,26 @@ impl ComplianceVerification {
             .ok_or_else(|| ComplianceError::NoSuchClaim(claim.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verify_reports_each_claim() {
+        let mut verification = ComplianceVerification::new_neuromorph_baseline();
+        let report = verification.verify();
+        assert!(report.overall_ok);
+        assert_eq!(report.checks.len(), verification.items.len() + 1);
+
+        verification.items[2].industry_best_practice = false;
+        let report = verification.verify();
+        assert!(!report.overall_ok);
+        let failed: Vec<_> = report.checks.iter().filter(|c| !c.passed).collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].name, "NeuroRights-Charter");
+
+        let empty = ComplianceVerification { items: Vec::new() }.verify();
+        assert!(!empty.overall_ok);
+    }
+}
@@ -1,13 +1,19 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Serialized as the string tags `"Alpha"`, `"Beta"`, `"Stable"`. The tags are
+/// pinned per variant so stored governance profiles keep loading even if the
+/// variants are renamed; lowercase spellings are accepted on input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CapabilityTier {
+    #[serde(rename = "Alpha", alias = "alpha")]
     Alpha,
+    #[serde(rename = "Beta", alias = "beta")]
     Beta,
+    #[serde(rename = "Stable", alias = "stable")]
     Stable,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CapabilityState {
     pub tier: CapabilityTier,
     pub can_self_modify: bool,
@@ -28,3 +34,44 @@ impl ReversalConditions {
             && self.no_safer_alternative
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tier_tags_are_pinned() {
+        for (tier, tag) in [
+            (CapabilityTier::Alpha, "\"Alpha\""),
+            (CapabilityTier::Beta, "\"Beta\""),
+            (CapabilityTier::Stable, "\"Stable\""),
+        ] {
+            assert_eq!(serde_json::to_string(&tier).unwrap(), tag);
+            assert_eq!(serde_json::from_str::<CapabilityTier>(tag).unwrap(), tier);
+        }
+        assert_eq!(
+            serde_json::from_str::<CapabilityTier>("\"stable\"").unwrap(),
+            CapabilityTier::Stable
+        );
+    }
+
+    #[test]
+    fn capability_state_round_trips() {
+        let state = CapabilityState {
+            tier: CapabilityTier::Beta,
+            can_self_modify: false,
+            can_request_transition: true,
+        };
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "tier": "Beta",
+                "can_self_modify": false,
+                "can_request_transition": true
+            })
+        );
+        let back: CapabilityState = serde_json::from_value(json).unwrap();
+        assert_eq!(back, state);
+    }
+}
@@ -1,6 +1,12 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
 use crate::capabilities::{CapabilityState, ReversalConditions};
 use crate::error::MorpheusError;
-use crate::rights::RightsLedgerEntry;
+use crate::rights::{Identity, RightsLedgerEntry};
+
+/// Minimum time between staging a downgrade and it taking effect.
+pub const DOWNGRADE_COOLDOWN_HOURS: i64 = 24;
 
 #[derive(Debug, Clone)]
 pub struct GovernanceContext {
@@ -8,16 +14,27 @@ pub struct GovernanceContext {
     pub capability_state: CapabilityState,
 }
 
+/// An allowed downgrade that has been staged but not yet applied. It only
+/// takes effect through `finalize_downgrade`, after `effective_after`, and
+/// with a second approver distinct from `approver`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingDowngrade {
+    pub from_state: CapabilityState,
+    pub next_state: CapabilityState,
+    pub approver: Identity,
+    pub requested_at: DateTime<Utc>,
+    pub effective_after: DateTime<Utc>,
+}
+
 impl GovernanceContext {
+    /// Stage a downgrade if the rights ledger and reversal conditions permit
+    /// it. Nothing changes until the returned `PendingDowngrade` is finalized.
     pub fn request_downgrade(
         &self,
         reversal: &ReversalConditions,
-    ) -> Result<CapabilityState, MorpheusError> {
-        if !self.rights_entry.is_reversal_allowed() {
-            return Err(MorpheusError::ReversalForbidden(
-                "neuromorphic reversal is globally disallowed for this subject".to_string(),
-            ));
-        }
+        approver: Identity,
+    ) -> Result<PendingDowngrade, MorpheusError> {
+        self.check_reversal_allowed()?;
         if !reversal.permits_downgrade() {
             return Err(MorpheusError::ReversalForbidden(
                 "reversal conditions not satisfied by sovereignty kernel".to_string(),
@@ -25,6 +42,119 @@ impl GovernanceContext {
         }
         let mut next = self.capability_state.clone();
         next.can_self_modify = false;
-        Ok(next)
+        let requested_at = Utc::now();
+        Ok(PendingDowngrade {
+            from_state: self.capability_state.clone(),
+            next_state: next,
+            approver,
+            requested_at,
+            effective_after: requested_at + Duration::hours(DOWNGRADE_COOLDOWN_HOURS),
+        })
+    }
+
+    /// Return the downgraded state once the cooldown has elapsed and a second,
+    /// different approver confirms. The rights ledger is re-checked here, so
+    /// revoking reversal during the cooldown cancels the downgrade.
+    pub fn finalize_downgrade(
+        &self,
+        pending: &PendingDowngrade,
+        confirmed_by: &Identity,
+    ) -> Result<CapabilityState, MorpheusError> {
+        self.check_reversal_allowed()?;
+        if pending.from_state != self.capability_state {
+            return Err(MorpheusError::ReversalForbidden(
+                "capability state changed since the downgrade was staged".to_string(),
+            ));
+        }
+        if confirmed_by.id == pending.approver.id {
+            return Err(MorpheusError::ReversalForbidden(
+                "downgrade must be confirmed by a second approver".to_string(),
+            ));
+        }
+        let now = Utc::now();
+        if now < pending.effective_after {
+            return Err(MorpheusError::ReversalForbidden(format!(
+                "downgrade cooldown runs until {}",
+                pending.effective_after.to_rfc3339()
+            )));
+        }
+        Ok(pending.next_state.clone())
+    }
+
+    fn check_reversal_allowed(&self) -> Result<(), MorpheusError> {
+        if !self.rights_entry.is_reversal_allowed() {
+            return Err(MorpheusError::ReversalForbidden(
+                "neuromorphic reversal is globally disallowed for this subject".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::capabilities::CapabilityTier;
+    use uuid::Uuid;
+
+    fn identity(label: &str) -> Identity {
+        Identity {
+            id: Uuid::new_v4(),
+            label: label.to_string(),
+        }
+    }
+
+    fn context() -> GovernanceContext {
+        let mut rights_entry =
+            RightsLedgerEntry::monotone_default("subject", "test", Utc::now().to_rfc3339());
+        rights_entry.allow_neuromorph_reversal = true;
+        GovernanceContext {
+            rights_entry,
+            capability_state: CapabilityState {
+                tier: CapabilityTier::Beta,
+                can_self_modify: true,
+                can_request_transition: true,
+            },
+        }
+    }
+
+    fn reversal() -> ReversalConditions {
+        ReversalConditions {
+            neuromorph_god_satisfied: true,
+            explicit_reversal_order: true,
+            no_safer_alternative: true,
+        }
+    }
+
+    #[test]
+    fn downgrade_is_staged_until_cooldown_and_second_approval() {
+        let ctx = context();
+        let first = identity("first");
+        let second = identity("second");
+        let mut pending = ctx.request_downgrade(&reversal(), first.clone()).unwrap();
+        assert_eq!(
+            pending.effective_after - pending.requested_at,
+            Duration::hours(DOWNGRADE_COOLDOWN_HOURS)
+        );
+        assert!(ctx.capability_state.can_self_modify);
+        assert!(ctx.finalize_downgrade(&pending, &second).is_err());
+
+        pending.effective_after = Utc::now() - Duration::seconds(1);
+        assert!(ctx.finalize_downgrade(&pending, &first).is_err());
+        let next = ctx.finalize_downgrade(&pending, &second).unwrap();
+        assert!(!next.can_self_modify);
+    }
+
+    #[test]
+    fn revoking_reversal_during_cooldown_cancels_downgrade() {
+        let mut ctx = context();
+        let mut pending = ctx.request_downgrade(&reversal(), identity("first")).unwrap();
+        pending.effective_after = Utc::now() - Duration::seconds(1);
+        ctx.rights_entry.allow_neuromorph_reversal = false;
+        assert!(ctx.finalize_downgrade(&pending, &identity("second")).is_err());
+
+        let mut blocked = reversal();
+        blocked.no_safer_alternative = false;
+        assert!(context().request_downgrade(&blocked, identity("first")).is_err());
     }
 }
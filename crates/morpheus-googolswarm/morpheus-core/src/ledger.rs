@@ -8,13 +8,104 @@ pub struct RightsLedger {
 }
 
 impl RightsLedger {
-    pub fn append(&mut self, entry: RightsLedgerEntry) -> Result<(), MorpheusError> {
+    pub fn append(&mut self, mut entry: RightsLedgerEntry) -> Result<(), MorpheusError> {
         if entry.allow_neuromorph_reversal {
             return Err(MorpheusError::RightsViolation(
                 "allow_neuromorph_reversal must remain false in Tier-1 ledger".to_string(),
             ));
         }
+        if !entry.verify_integrity() {
+            return Err(MorpheusError::LedgerError(
+                "entry content hash does not match its fields".to_string(),
+            ));
+        }
+        entry.chain_to(self.head_hash());
         self.entries.push(entry);
         Ok(())
     }
+
+    /// Content hash of the most recent entry.
+    pub fn head_hash(&self) -> Option<String> {
+        self.entries.last().map(|e| e.content_hash.clone())
+    }
+
+    /// Check every entry's own hash and its link to the previous entry.
+    pub fn verify_chain(&self) -> Result<(), MorpheusError> {
+        let mut expected_prev: Option<&str> = None;
+        for (i, entry) in self.entries.iter().enumerate() {
+            if !entry.verify_integrity() {
+                return Err(MorpheusError::LedgerError(format!(
+                    "entry {i} failed integrity check"
+                )));
+            }
+            if entry.prev_hash.as_deref() != expected_prev {
+                return Err(MorpheusError::LedgerError(format!(
+                    "entry {i} is not chained to its predecessor"
+                )));
+            }
+            expected_prev = Some(entry.content_hash.as_str());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(statement: &str) -> RightsLedgerEntry {
+        RightsLedgerEntry::monotone_default(
+            "subject",
+            statement,
+            "2025-01-01T00:00:00Z".to_string(),
+        )
+    }
+
+    fn ledger() -> RightsLedger {
+        let mut ledger = RightsLedger::default();
+        for statement in ["first", "second", "third"] {
+            ledger.append(entry(statement)).unwrap();
+        }
+        ledger
+    }
+
+    #[test]
+    fn append_links_to_previous_hash() {
+        let ledger = ledger();
+        assert_eq!(ledger.entries[0].prev_hash, None);
+        for pair in ledger.entries.windows(2) {
+            assert_eq!(
+                pair[1].prev_hash.as_deref(),
+                Some(pair[0].content_hash.as_str())
+            );
+        }
+        assert_eq!(
+            ledger.head_hash().as_deref(),
+            Some(ledger.entries[2].content_hash.as_str())
+        );
+        ledger.verify_chain().unwrap();
+    }
+
+    #[test]
+    fn mutated_entry_fails_verification() {
+        let mut tampered = ledger();
+        tampered.entries[1].statement = "rewritten".to_string();
+        assert!(tampered.verify_chain().is_err());
+
+        // Re-sealing the edited entry still breaks the link from its successor.
+        tampered.entries[1].content_hash = tampered.entries[1].compute_hash();
+        assert!(tampered.verify_chain().is_err());
+    }
+
+    #[test]
+    fn legacy_entry_without_hash_is_rejected() {
+        let mut legacy = entry("legacy");
+        legacy.content_hash = String::new();
+        let mut ledger = RightsLedger::default();
+        assert!(matches!(
+            ledger.append(legacy),
+            Err(MorpheusError::LedgerError(_))
+        ));
+        assert!(ledger.entries.is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use uuid::Uuid;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -21,11 +22,17 @@ pub struct RightsLedgerEntry {
     pub allow_neuromorph_reversal: bool,
     pub timestamp_utc: String,
     pub statement: String,
+    /// Content hash of the preceding ledger entry, if any.
+    #[serde(default)]
+    pub prev_hash: Option<String>,
+    /// SHA-256 over this entry's fields (including `prev_hash`), hex-encoded.
+    #[serde(default)]
+    pub content_hash: String,
 }
 
 impl RightsLedgerEntry {
     pub fn monotone_default(subject_label: &str, statement: &str, timestamp_utc: String) -> Self {
-        Self {
+        let mut entry = Self {
             subject: Identity {
                 id: Uuid::new_v4(),
                 label: subject_label.to_string(),
@@ -34,10 +41,39 @@ impl RightsLedgerEntry {
             allow_neuromorph_reversal: false,
             timestamp_utc,
             statement: statement.to_string(),
-        }
+            prev_hash: None,
+            content_hash: String::new(),
+        };
+        entry.content_hash = entry.compute_hash();
+        entry
     }
 
     pub fn is_reversal_allowed(&self) -> bool {
         self.allow_neuromorph_reversal
     }
+
+    /// Hash every field except `content_hash` itself.
+    pub fn compute_hash(&self) -> String {
+        let material = serde_json::json!({
+            "subject": self.subject,
+            "immutable_neurorights_level": self.immutable_neurorights_level,
+            "allow_neuromorph_reversal": self.allow_neuromorph_reversal,
+            "timestamp_utc": self.timestamp_utc,
+            "statement": self.statement,
+            "prev_hash": self.prev_hash,
+        });
+        let digest = Sha256::digest(material.to_string().as_bytes());
+        digest.iter().map(|b| format!("{b:02x}")).collect()
+    }
+
+    /// True if `content_hash` still matches the entry's fields.
+    pub fn verify_integrity(&self) -> bool {
+        !self.content_hash.is_empty() && self.content_hash == self.compute_hash()
+    }
+
+    /// Link this entry to its predecessor and re-seal the content hash.
+    pub fn chain_to(&mut self, prev_hash: Option<String>) {
+        self.prev_hash = prev_hash;
+        self.content_hash = self.compute_hash();
+    }
 }
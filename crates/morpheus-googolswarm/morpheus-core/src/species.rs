@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum SpeciesKind {
     Human,
     Synthetic,
@@ -8,6 +8,26 @@ pub enum SpeciesKind {
     Other(String),
 }
 
+impl SpeciesKind {
+    /// Normalize case/whitespace and map known aliases onto canonical variants.
+    /// Only genuinely unknown kinds fall back to `Other`, stored in normalized form.
+    pub fn parse(s: &str) -> SpeciesKind {
+        let normalized = s
+            .trim()
+            .to_ascii_lowercase()
+            .split(|c: char| c.is_whitespace() || c == '_' || c == '-')
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        match normalized.as_str() {
+            "human" | "homo-sapiens" | "organic" => SpeciesKind::Human,
+            "synthetic" | "machine" | "ai" | "artificial" => SpeciesKind::Synthetic,
+            "hybrid" | "human-hybrid" | "cybernetic" | "augmented-human" => SpeciesKind::Hybrid,
+            _ => SpeciesKind::Other(normalized),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BiophysicalEnvelope {
     pub species: SpeciesKind,
@@ -16,3 +36,40 @@ pub struct BiophysicalEnvelope {
     pub roH_monotone: bool,
     pub no_cross_species_signals: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_normalizes_case_and_aliases() {
+        for (input, expected) in [
+            ("human", SpeciesKind::Human),
+            ("  HUMAN ", SpeciesKind::Human),
+            ("Homo Sapiens", SpeciesKind::Human),
+            ("organic", SpeciesKind::Human),
+            ("Synthetic", SpeciesKind::Synthetic),
+            ("AI", SpeciesKind::Synthetic),
+            ("machine", SpeciesKind::Synthetic),
+            ("HUMAN-HYBRID", SpeciesKind::Hybrid),
+            ("human_hybrid", SpeciesKind::Hybrid),
+            ("Augmented Human", SpeciesKind::Hybrid),
+            ("cybernetic", SpeciesKind::Hybrid),
+        ] {
+            assert_eq!(SpeciesKind::parse(input), expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn unknown_kinds_fall_back_to_normalized_other() {
+        assert_eq!(
+            SpeciesKind::parse("Uplifted  Octopus"),
+            SpeciesKind::Other("uplifted-octopus".to_string())
+        );
+        assert_eq!(
+            SpeciesKind::parse("gryphon"),
+            SpeciesKind::Other("gryphon".to_string())
+        );
+        assert_eq!(SpeciesKind::parse(""), SpeciesKind::Other(String::new()));
+    }
+}
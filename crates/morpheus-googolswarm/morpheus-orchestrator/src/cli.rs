@@ -1,6 +1,6 @@
 use crate::config::OrchestratorConfig;
-use crate::interpreter::interpret_spec_file;
-use clap::{Parser, Subcommand};
+use crate::interpreter::{interpret_spec_file, validate_spec_file};
+use clap::{Parser, Subcommand, ValueEnum};
 use anyhow::Result;
 
 #[derive(Debug, Parser)]
@@ -18,6 +18,19 @@ pub enum Commands {
         #[arg(short, long)]
         spec: Option<String>,
     },
+    /// Validate an ALN spec; exits nonzero when any violation is found
+    Validate {
+        #[arg(short, long)]
+        spec: Option<String>,
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    Text,
+    Json,
 }
 
 pub fn run() -> Result<()> {
@@ -30,6 +43,28 @@ pub fn run() -> Result<()> {
             let json = serde_json::to_string_pretty(&profile)?;
             println!("{json}");
         }
+        Commands::Validate { spec, format } => {
+            let cfg = OrchestratorConfig::from_env_or_default();
+            let path = spec.map(std::path::PathBuf::from).unwrap_or(cfg.spec_path);
+            let report = validate_spec_file(&path)?;
+            match format {
+                OutputFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+                OutputFormat::Text => {
+                    for v in &report.violations {
+                        let location = v.location.as_deref().unwrap_or("-");
+                        println!("{}: {} [{}] {}", path.display(), location, v.code, v.message);
+                    }
+                    println!(
+                        "{}: {}",
+                        path.display(),
+                        if report.ok { "ok" } else { "FAILED" }
+                    );
+                }
+            }
+            if !report.ok {
+                std::process::exit(1);
+            }
+        }
     }
     Ok(())
 }
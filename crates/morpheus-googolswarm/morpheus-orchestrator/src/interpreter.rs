@@ -1,5 +1,5 @@
 use anyhow::Result;
-use morpheus_spec_aln::to_governance_profile;
+use morpheus_spec_aln::{to_governance_profile, validate_spec, ValidationReport};
 use std::fs;
 
 pub fn interpret_spec_file(path: &std::path::Path) -> Result<morpheus_spec_aln::GovernanceProfile> {
@@ -7,3 +7,8 @@ pub fn interpret_spec_file(path: &std::path::Path) -> Result<morpheus_spec_aln::
     let profile = to_governance_profile(&content)?;
     Ok(profile)
 }
+
+pub fn validate_spec_file(path: &std::path::Path) -> Result<ValidationReport> {
+    let content = fs::read_to_string(path)?;
+    Ok(validate_spec(&content))
+}
@@ -6,9 +6,25 @@ pub enum AlnKey {
     Capabilities,
     Species,
     ReversalPolicy,
+    Approver,
     Custom(String),
 }
 
+impl AlnKey {
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "SECTION" => AlnKey::Section,
+            "ROLE" => AlnKey::Role,
+            "RIGHTS" => AlnKey::Rights,
+            "CAPABILITIES" => AlnKey::Capabilities,
+            "SPECIES" => AlnKey::Species,
+            "REVERSAL_POLICY" => AlnKey::ReversalPolicy,
+            "APPROVER" => AlnKey::Approver,
+            other => AlnKey::Custom(other.to_string()),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct AlnProperty {
     pub key: AlnKey,
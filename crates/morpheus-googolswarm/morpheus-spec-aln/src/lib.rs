@@ -1,6 +1,10 @@
 pub mod aln;
 pub mod model;
 pub mod parser;
+pub mod validate;
 
 pub use crate::model::{GovernanceProfile, ParsedError};
-pub use crate::parser::{parse_aln, to_governance_profile};
+pub use crate::parser::{
+    parse_aln, parse_aln_with, to_governance_profile, to_governance_profile_with, ParseOptions,
+};
+pub use crate::validate::{validate_spec, ValidationReport, Violation};
@@ -1,19 +1,25 @@
 use morpheus_core::capabilities::CapabilityState;
 use morpheus_core::rights::RightsLedgerEntry;
 use morpheus_core::species::BiophysicalEnvelope;
+use serde::Serialize;
+use thiserror::Error;
 
-#[derive(Debug)]
+#[derive(Debug, Error)]
 pub enum ParsedError {
+    #[error("invalid spec: {0}")]
     Invalid(String),
+    /// Strict mode only: a key that is neither built in nor allow-listed.
+    #[error("unknown key '{key}' on line {line}")]
+    UnknownKey { key: String, line: usize },
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ParsedSection {
     Morpheus,
     Other(String),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ParsedRoleKind {
     NeuromorphGod,
     Host,
@@ -33,32 +39,61 @@ impl ParsedRoleKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct SpeciesProfile {
     pub envelope: BiophysicalEnvelope,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum ReversalPermission {
     DisallowNeuromorphReversal,
+    AllowWithApproval,
+    AllowNeuromorphReversal,
 }
 
-#[derive(Debug, Clone)]
+impl ReversalPermission {
+    /// Parse a `REVERSAL_POLICY=` value. Only the leading clause is read, e.g.
+    /// `allowneuromorphreversal=false; ...`; absent or malformed values disallow.
+    pub fn parse(value: &str) -> Self {
+        let clause = value
+            .split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        let setting = match clause.split_once('=') {
+            Some((k, v)) if k.trim() == "allowneuromorphreversal" => v.trim(),
+            Some(_) => return ReversalPermission::DisallowNeuromorphReversal,
+            None => clause.as_str(),
+        };
+        match setting {
+            "approval" | "with-approval" | "allow-with-approval" => {
+                ReversalPermission::AllowWithApproval
+            }
+            "true" | "allow" => ReversalPermission::AllowNeuromorphReversal,
+            _ => ReversalPermission::DisallowNeuromorphReversal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct ReversalSettings {
     pub permission: ReversalPermission,
+    /// Set from `APPROVER=`; required for any non-disallow permission.
+    pub approver: Option<String>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ReversalPolicyProfile {
     pub settings: ReversalSettings,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ParsedDocument {
     pub raw: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct GovernanceProfile {
     pub section: ParsedSection,
     pub document: ParsedDocument,
@@ -68,3 +103,28 @@ pub struct GovernanceProfile {
     pub species_profile: SpeciesProfile,
     pub reversal_policy: ReversalPolicyProfile,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reversal_permission_spellings() {
+        use ReversalPermission::*;
+        for (input, expected) in [
+            ("approval", AllowWithApproval),
+            ("with-approval", AllowWithApproval),
+            ("Allow-With-Approval", AllowWithApproval),
+            ("true", AllowNeuromorphReversal),
+            ("ALLOW", AllowNeuromorphReversal),
+            ("allowneuromorphreversal=true; audited", AllowNeuromorphReversal),
+            ("allowneuromorphreversal = approval", AllowWithApproval),
+            ("allowneuromorphreversal=false", DisallowNeuromorphReversal),
+            ("otherkey=true", DisallowNeuromorphReversal),
+            ("", DisallowNeuromorphReversal),
+            ("maybe", DisallowNeuromorphReversal),
+        ] {
+            assert_eq!(ReversalPermission::parse(input), expected, "{input:?}");
+        }
+    }
+}
@@ -11,23 +11,47 @@ use crate::model::ParsedSection;
 use crate::model::ParsedDocument;
 use crate::model::ParsedError;
 
+/// How the parser treats keys it doesn't recognize.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Reject custom keys not listed in `allowed_custom_keys`, so a typo'd
+    /// directive fails loudly instead of being silently ignored.
+    pub strict: bool,
+    /// Custom keys accepted in strict mode.
+    pub allowed_custom_keys: Vec<String>,
+}
+
+impl ParseOptions {
+    pub fn strict(allowed_custom_keys: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            strict: true,
+            allowed_custom_keys: allowed_custom_keys.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Lenient parse: unknown keys are kept as `AlnKey::Custom`.
 pub fn parse_aln(input: &str) -> Result<AlnDocument, ParsedError> {
+    parse_aln_with(input, &ParseOptions::default())
+}
+
+pub fn parse_aln_with(input: &str, options: &ParseOptions) -> Result<AlnDocument, ParsedError> {
     let mut doc = AlnDocument::default();
-    for line in input.lines() {
+    for (idx, line) in input.lines().enumerate() {
         let trimmed = line.trim();
         if trimmed.is_empty() || trimmed.starts_with('#') {
             continue;
         }
         if let Some((k, v)) = trimmed.split_once('=') {
-            let key = match k.trim() {
-                "SECTION" => AlnKey::Section,
-                "ROLE" => AlnKey::Role,
-                "RIGHTS" => AlnKey::Rights,
-                "CAPABILITIES" => AlnKey::Capabilities,
-                "SPECIES" => AlnKey::Species,
-                "REVERSAL_POLICY" => AlnKey::ReversalPolicy,
-                other => AlnKey::Custom(other.to_string()),
-            };
+            let key = AlnKey::from_name(k.trim());
+            if let AlnKey::Custom(name) = &key {
+                if options.strict && !options.allowed_custom_keys.iter().any(|a| a == name) {
+                    return Err(ParsedError::UnknownKey {
+                        key: name.clone(),
+                        line: idx + 1,
+                    });
+                }
+            }
             let value = v.trim().trim_matches('"').to_string();
             doc.properties.push(AlnProperty { key, value });
         }
@@ -36,7 +60,14 @@ pub fn parse_aln(input: &str) -> Result<AlnDocument, ParsedError> {
 }
 
 pub fn to_governance_profile(input: &str) -> Result<GovernanceProfile, ParsedError> {
-    let doc = parse_aln(input)?;
+    to_governance_profile_with(input, &ParseOptions::default())
+}
+
+pub fn to_governance_profile_with(
+    input: &str,
+    options: &ParseOptions,
+) -> Result<GovernanceProfile, ParsedError> {
+    let doc = parse_aln_with(input, options)?;
     let role_kind = ParsedRoleKind::from_values(doc.get_values(&AlnKey::Role));
     let rights = RightsLedgerEntry::monotone_default(
         "Morpheus-Subject",
@@ -48,18 +79,42 @@ pub fn to_governance_profile(input: &str) -> Result<GovernanceProfile, ParsedErr
         can_self_modify: false,
         can_request_transition: true,
     };
+    // SPECIES="HUMAN-HYBRID; RoH<=0.30; ..." -- the leading clause names the kind.
+    let species = doc
+        .get_values(&AlnKey::Species)
+        .first()
+        .and_then(|v| v.split(';').next())
+        .map(SpeciesKind::parse)
+        .unwrap_or(SpeciesKind::Hybrid);
     let species_profile = SpeciesProfile {
         envelope: BiophysicalEnvelope {
-            species: SpeciesKind::Hybrid,
+            species,
             min_safe_roh: 0.0,
             max_safe_roh: 0.30,
             roH_monotone: true,
             no_cross_species_signals: true,
         },
     };
+    let approver = doc
+        .get_values(&AlnKey::Approver)
+        .first()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let requested = doc
+        .get_values(&AlnKey::ReversalPolicy)
+        .first()
+        .map(|v| ReversalPermission::parse(v))
+        .unwrap_or(ReversalPermission::DisallowNeuromorphReversal);
+    // Anything looser than disallow needs a named approver in the spec.
+    let permission = if approver.is_some() {
+        requested
+    } else {
+        ReversalPermission::DisallowNeuromorphReversal
+    };
     let reversal_policy = ReversalPolicyProfile {
         settings: ReversalSettings {
-            permission: ReversalPermission::DisallowNeuromorphReversal,
+            permission,
+            approver,
         },
     };
     Ok(GovernanceProfile {
@@ -72,3 +127,78 @@ pub fn to_governance_profile(input: &str) -> Result<GovernanceProfile, ParsedErr
         reversal_policy,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPEC: &str = "SECTION=core\nROLE=operator\n\n# site-specific\nSITE_TAG=lab-7\n";
+
+    #[test]
+    fn strict_mode_rejects_unknown_key_with_line() {
+        let err = parse_aln_with(SPEC, &ParseOptions::strict(Vec::<String>::new())).unwrap_err();
+        match err {
+            ParsedError::UnknownKey { key, line } => {
+                assert_eq!(key, "SITE_TAG");
+                assert_eq!(line, 5);
+            }
+            other => panic!("expected UnknownKey, got {other:?}"),
+        }
+        assert!(to_governance_profile_with(SPEC, &ParseOptions::strict(["OTHER"])).is_err());
+    }
+
+    #[test]
+    fn strict_mode_accepts_allow_listed_key() {
+        let doc = parse_aln_with(SPEC, &ParseOptions::strict(["SITE_TAG"])).unwrap();
+        assert_eq!(
+            doc.get_values(&AlnKey::Custom("SITE_TAG".to_string())),
+            ["lab-7"]
+        );
+    }
+
+    #[test]
+    fn lenient_mode_keeps_custom_keys() {
+        let doc = parse_aln(SPEC).unwrap();
+        assert_eq!(doc.properties.len(), 3);
+        assert_eq!(
+            doc.properties[2].key,
+            AlnKey::Custom("SITE_TAG".to_string())
+        );
+        assert_eq!(doc.properties[2].value, "lab-7");
+    }
+
+    fn reversal(spec_tail: &str) -> ReversalSettings {
+        to_governance_profile(&format!("SECTION=core\nROLE=operator\n{spec_tail}"))
+            .unwrap()
+            .reversal_policy
+            .settings
+    }
+
+    #[test]
+    fn reversal_without_approver_is_disallowed() {
+        for tail in [
+            "REVERSAL_POLICY=allow\n",
+            "REVERSAL_POLICY=with-approval\nAPPROVER=\n",
+            "REVERSAL_POLICY=with-approval\nAPPROVER=\"  \"\n",
+        ] {
+            let settings = reversal(tail);
+            assert_eq!(
+                settings.permission,
+                ReversalPermission::DisallowNeuromorphReversal,
+                "{tail:?}"
+            );
+            assert_eq!(settings.approver, None);
+        }
+    }
+
+    #[test]
+    fn reversal_with_named_approver_is_kept() {
+        let settings = reversal("REVERSAL_POLICY=with-approval\nAPPROVER=ethics-board\n");
+        assert_eq!(settings.permission, ReversalPermission::AllowWithApproval);
+        assert_eq!(settings.approver.as_deref(), Some("ethics-board"));
+        assert_eq!(
+            reversal("APPROVER=ethics-board\n").permission,
+            ReversalPermission::DisallowNeuromorphReversal
+        );
+    }
+}
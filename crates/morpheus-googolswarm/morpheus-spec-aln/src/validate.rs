@@ -0,0 +1,188 @@
+use crate::aln::{AlnDocument, AlnKey, AlnProperty};
+use crate::model::ReversalPermission;
+use morpheus_core::species::SpeciesKind;
+use serde::{Deserialize, Serialize};
+
+/// A single coded governance finding; `location` is e.g. `"line 4"` when known.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Violation {
+    pub code: String,
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Violation {
+    fn new(code: &str, message: impl Into<String>, line: Option<usize>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+            location: line.map(|n| format!("line {n}")),
+        }
+    }
+}
+
+/// Machine-readable validation outcome: `{ "ok": bool, "violations": [...] }`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub ok: bool,
+    pub violations: Vec<Violation>,
+}
+
+const REQUIRED_KEYS: [(AlnKey, &str); 3] = [
+    (AlnKey::Section, "SECTION"),
+    (AlnKey::Role, "ROLE"),
+    (AlnKey::Species, "SPECIES"),
+];
+
+/// Check an ALN spec for problems that `to_governance_profile` would
+/// otherwise paper over with defaults.
+pub fn validate_spec(input: &str) -> ValidationReport {
+    let mut violations = Vec::new();
+    let mut doc = AlnDocument::default();
+    let mut lines_by_key: Vec<(AlnKey, usize)> = Vec::new();
+
+    for (idx, line) in input.lines().enumerate() {
+        let line_no = idx + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        let Some((k, v)) = trimmed.split_once('=') else {
+            violations.push(Violation::new(
+                "ALN001",
+                "line is not a KEY=VALUE property and is ignored by the parser",
+                Some(line_no),
+            ));
+            continue;
+        };
+        let key = AlnKey::from_name(k.trim());
+        lines_by_key.push((key.clone(), line_no));
+        doc.properties.push(AlnProperty {
+            key,
+            value: v.trim().trim_matches('"').to_string(),
+        });
+    }
+
+    let line_of = |key: &AlnKey| {
+        lines_by_key
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, n)| *n)
+    };
+
+    for (key, name) in REQUIRED_KEYS.iter() {
+        if doc.get_values(key).is_empty() {
+            violations.push(Violation::new(
+                "ALN002",
+                format!("required property {name} is missing"),
+                None,
+            ));
+        }
+    }
+
+    if let Some(species) = doc.get_values(&AlnKey::Species).first() {
+        let kind = SpeciesKind::parse(species.split(';').next().unwrap_or_default());
+        if let SpeciesKind::Other(other) = kind {
+            violations.push(Violation::new(
+                "ALN003",
+                format!("unrecognized species kind '{other}'"),
+                line_of(&AlnKey::Species),
+            ));
+        }
+    }
+
+    if let Some(policy) = doc.get_values(&AlnKey::ReversalPolicy).first() {
+        let requested = ReversalPermission::parse(policy);
+        let has_approver = doc
+            .get_values(&AlnKey::Approver)
+            .iter()
+            .any(|v| !v.trim().is_empty());
+        if requested != ReversalPermission::DisallowNeuromorphReversal && !has_approver {
+            violations.push(Violation::new(
+                "ALN004",
+                "REVERSAL_POLICY permits reversal but no APPROVER is named; it will be forced to disallow",
+                line_of(&AlnKey::ReversalPolicy),
+            ));
+        }
+    }
+
+    ValidationReport {
+        ok: violations.is_empty(),
+        violations,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID: &str = "SECTION=core\nROLE=operator\nSPECIES=human\n";
+
+    fn codes(report: &ValidationReport) -> Vec<&str> {
+        report.violations.iter().map(|v| v.code.as_str()).collect()
+    }
+
+    #[test]
+    fn complete_spec_is_ok() {
+        let report = validate_spec(VALID);
+        assert!(report.ok, "{:?}", report.violations);
+    }
+
+    #[test]
+    fn non_property_line_is_aln001() {
+        let report = validate_spec(&format!("{VALID}# comment\n\nnot a property\n"));
+        assert!(!report.ok);
+        assert_eq!(codes(&report), ["ALN001"]);
+        assert_eq!(report.violations[0].location.as_deref(), Some("line 6"));
+    }
+
+    #[test]
+    fn missing_required_keys_are_aln002() {
+        let report = validate_spec("ROLE=operator\n");
+        assert_eq!(codes(&report), ["ALN002", "ALN002"]);
+        assert!(report.violations[0].message.contains("SECTION"));
+        assert!(report.violations[1].message.contains("SPECIES"));
+        assert!(report.violations.iter().all(|v| v.location.is_none()));
+    }
+
+    #[test]
+    fn unknown_species_is_aln003() {
+        let report = validate_spec("SECTION=core\nROLE=operator\nSPECIES=\"gryphon;wild\"\n");
+        assert_eq!(codes(&report), ["ALN003"]);
+        assert!(report.violations[0].message.contains("gryphon"));
+        assert_eq!(report.violations[0].location.as_deref(), Some("line 3"));
+    }
+
+    #[test]
+    fn reversal_without_approver_is_aln004() {
+        let report = validate_spec(&format!("{VALID}REVERSAL_POLICY=with-approval\nAPPROVER=\n"));
+        assert_eq!(codes(&report), ["ALN004"]);
+        assert_eq!(report.violations[0].location.as_deref(), Some("line 4"));
+
+        let approved = validate_spec(&format!(
+            "{VALID}REVERSAL_POLICY=with-approval\nAPPROVER=ethics-board\n"
+        ));
+        assert!(approved.ok, "{:?}", approved.violations);
+        assert!(validate_spec(&format!("{VALID}REVERSAL_POLICY=disallow\n")).ok);
+    }
+
+    #[test]
+    fn report_json_shape() {
+        let report = validate_spec("SECTION=core\nROLE=operator\nSPECIES=human\nbogus\n");
+        assert_eq!(
+            serde_json::to_value(&report).unwrap(),
+            serde_json::json!({
+                "ok": false,
+                "violations": [{
+                    "code": "ALN001",
+                    "message": "line is not a KEY=VALUE property and is ignored by the parser",
+                    "location": "line 4"
+                }]
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(validate_spec(VALID)).unwrap(),
+            serde_json::json!({ "ok": true, "violations": [] })
+        );
+    }
+}
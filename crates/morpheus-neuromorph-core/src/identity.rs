@@ -0,0 +1,113 @@
+use chrono::{DateTime, Duration, Utc};
+use morpheus_security::SignatureAlgorithm;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+/// How long a signed identity stays acceptable after it was issued.
+pub const DEFAULT_IDENTITY_MAX_AGE_SECS: i64 = 300;
+/// Live nonces remembered for replay detection; new identities are refused past this.
+pub const DEFAULT_NONCE_CAPACITY: usize = 4096;
+/// Tolerated clock drift for identities stamped slightly in the future.
+pub const IDENTITY_CLOCK_SKEW_SECS: i64 = 30;
+
+/// An identity signature bound to a one-time nonce and issue time, so a
+/// captured signature stops working once it is used or goes stale.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignedIdentity {
+    pub identity: String,
+    pub nonce: String,
+    pub issued_at: DateTime<Utc>,
+    /// Identities signed before algorithms were recorded used HMAC-SHA256.
+    #[serde(default)]
+    pub algorithm: SignatureAlgorithm,
+    pub signature: Vec<u8>,
+}
+
+impl SignedIdentity {
+    /// The exact bytes covered by `signature`.
+    pub(crate) fn payload(identity: &str, nonce: &str, issued_at: DateTime<Utc>) -> Vec<u8> {
+        // A JSON array keeps field boundaries unambiguous whatever the identity contains.
+        serde_json::to_vec(&(identity, nonce, issued_at.to_rfc3339()))
+            .expect("string tuple always serializes")
+    }
+}
+
+/// Why `NonceCache::insert` refused a nonce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NonceRejection {
+    /// The nonce was already accepted inside the window.
+    Replayed,
+    /// Every slot holds a nonce still inside `max_age`.
+    CacheFull,
+}
+
+/// Bounded record of nonces already accepted.
+///
+/// Only entries older than `max_age` are evicted, since the age check rejects
+/// them anyway. A live nonce is never forgotten: when every slot is live, new
+/// identities are refused until one expires, so size the capacity above the
+/// number of identities expected per window.
+#[derive(Debug)]
+pub struct NonceCache {
+    max_age: Duration,
+    capacity: usize,
+    order: VecDeque<(DateTime<Utc>, String)>,
+    seen: HashSet<String>,
+}
+
+impl NonceCache {
+    pub fn new(max_age: Duration, capacity: usize) -> Self {
+        Self {
+            max_age,
+            capacity: capacity.max(1),
+            order: VecDeque::new(),
+            seen: HashSet::new(),
+        }
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    /// Record `nonce`, refusing it if it was already seen or the cache is
+    /// full of live nonces.
+    pub fn insert(
+        &mut self,
+        nonce: &str,
+        issued_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> Result<(), NonceRejection> {
+        self.evict_expired(now);
+        if self.seen.contains(nonce) {
+            return Err(NonceRejection::Replayed);
+        }
+        if self.order.len() >= self.capacity {
+            return Err(NonceRejection::CacheFull);
+        }
+        self.seen.insert(nonce.to_string());
+        self.order.push_back((issued_at, nonce.to_string()));
+        Ok(())
+    }
+
+    fn evict_expired(&mut self, now: DateTime<Utc>) {
+        let max_age = self.max_age;
+        let seen = &mut self.seen;
+        // Issue times arrive out of order, so scan the whole queue.
+        self.order.retain(|(at, nonce)| {
+            let live = now - *at <= max_age;
+            if !live {
+                seen.remove(nonce);
+            }
+            live
+        });
+    }
+}
+
+impl Default for NonceCache {
+    fn default() -> Self {
+        Self::new(
+            Duration::seconds(DEFAULT_IDENTITY_MAX_AGE_SECS),
+            DEFAULT_NONCE_CAPACITY,
+        )
+    }
+}
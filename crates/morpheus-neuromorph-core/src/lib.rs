@@ -1,10 +1,25 @@
 use morpheus_compliance::ComplianceVerification;
 use morpheus_config::ProviderConfig;
-use morpheus_registry::{EndpointRegistry, EndpointStatus};
-use morpheus_security::{generate_random_secret, hmac_sign, SecurityProfile};
+use morpheus_registry::{EndpointRegistry, EndpointStatus, KeyRefError};
+use morpheus_security::{
+    generate_random_secret, generate_random_secret_with, sign, verify, verifying_key,
+    SecurityProfile,
+};
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use thiserror::Error;
 
+mod identity;
+
+pub use identity::{
+    NonceCache, NonceRejection, SignedIdentity, DEFAULT_IDENTITY_MAX_AGE_SECS,
+    DEFAULT_NONCE_CAPACITY, IDENTITY_CLOCK_SKEW_SECS,
+};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NeuromorphRights {
     pub free_knowledge: bool,
@@ -43,31 +58,38 @@ impl Default for NeuromorphDiscipline {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct MorpheusContext {
     pub rights: NeuromorphRights,
     pub discipline: NeuromorphDiscipline,
     pub provider_config: ProviderConfig,
 }
 
-impl Default for MorpheusContext {
-    fn default() -> Self {
-        Self {
-            rights: NeuromorphRights::default(),
-            discipline: NeuromorphDiscipline::default(),
-            provider_config: ProviderConfig::default(),
-        }
-    }
-}
-
 #[derive(Debug, Error)]
 pub enum MorpheusError {
     #[error("config error: {0}")]
     Config(#[from] morpheus_config::ConfigError),
     #[error("security error: {0}")]
     Security(#[from] morpheus_security::SecurityError),
+    #[error("registry error: {0}")]
+    Registry(#[from] KeyRefError),
     #[error("rights violation: {0}")]
     RightsViolation(String),
+    #[error("identity rejected: {0}")]
+    IdentityRejected(String),
+}
+
+/// A forbidden reversal/rollback/downgrade request, as handed to an audit sink.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReversalAttempt {
+    pub action: String,
+    pub attempted_at: DateTime<Utc>,
+    pub identity: Option<String>,
+}
+
+/// Receives every reversal attempt rejected by `enforce_no_reversal`.
+pub trait ReversalAuditSink: Send + Sync {
+    fn record(&self, attempt: &ReversalAttempt);
 }
 
 pub struct MorpheusEngine {
@@ -75,6 +97,11 @@ pub struct MorpheusEngine {
     pub registry: EndpointRegistry,
     pub security_profile: SecurityProfile,
     pub compliance: ComplianceVerification,
+    audit_sink: Option<Arc<dyn ReversalAuditSink>>,
+    signing_key: Vec<u8>,
+    nonce_cache: Mutex<NonceCache>,
+    /// Seeded RNG for keys and nonces; `None` draws from OS entropy.
+    rng: Mutex<Option<StdRng>>,
 }
 
 impl MorpheusEngine {
@@ -90,10 +117,65 @@ impl MorpheusEngine {
             registry,
             security_profile,
             compliance,
+            audit_sink: None,
+            signing_key: generate_random_secret().to_vec(),
+            nonce_cache: Mutex::new(NonceCache::default()),
+            rng: Mutex::new(None),
         })
     }
 
+    /// Make the engine's randomness reproducible: the signing key, identity
+    /// nonces, endpoint ids and `Random` endpoint selection all derive from
+    /// `seed`. Replaces the registry, so call it before registering
+    /// endpoints. For tests only; seeded keys are predictable.
+    pub fn with_rng_seed(mut self, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        self.signing_key = generate_random_secret_with(&mut rng).to_vec();
+        self.rng = Mutex::new(Some(rng));
+        self.registry = EndpointRegistry::with_seed(seed);
+        self
+    }
+
+    fn random_secret(&self) -> [u8; 32] {
+        match self.rng.lock().as_mut() {
+            Some(rng) => generate_random_secret_with(rng),
+            None => generate_random_secret(),
+        }
+    }
+
+    /// Report forbidden reversal attempts to `sink`. Without a sink,
+    /// rejections are not recorded anywhere.
+    pub fn with_audit_sink(mut self, sink: Arc<dyn ReversalAuditSink>) -> Self {
+        self.audit_sink = Some(sink);
+        self
+    }
+
+    /// Sign and verify identities with `key` instead of the per-engine
+    /// random key, so signatures survive restarts and other engines sharing
+    /// the key can verify them.
+    pub fn with_signing_key(mut self, key: impl Into<Vec<u8>>) -> Self {
+        self.signing_key = key.into();
+        self
+    }
+
+    /// Accept signed identities up to `max_age` old, remembering at most
+    /// `nonce_capacity` nonces for replay detection.
+    pub fn with_identity_window(mut self, max_age: Duration, nonce_capacity: usize) -> Self {
+        self.nonce_cache = Mutex::new(NonceCache::new(max_age, nonce_capacity));
+        self
+    }
+
     pub fn enforce_no_reversal(&self, action: &str) -> Result<(), MorpheusError> {
+        self.enforce_no_reversal_for(action, None)
+    }
+
+    /// Like `enforce_no_reversal`, attributing the attempt to `identity`
+    /// in the audit trail.
+    pub fn enforce_no_reversal_for(
+        &self,
+        action: &str,
+        identity: Option<&str>,
+    ) -> Result<(), MorpheusError> {
         if self
             .ctx
             .rights
@@ -103,6 +185,13 @@ impl MorpheusEngine {
                 "rollback" | "reverse" | "downgrade" | "revert" | "undo"
             )
         {
+            if let Some(sink) = &self.audit_sink {
+                sink.record(&ReversalAttempt {
+                    action: action.to_string(),
+                    attempted_at: Utc::now(),
+                    identity: identity.map(str::to_string),
+                });
+            }
             return Err(MorpheusError::RightsViolation(
                 "reversals/rollbacks/downgrades disallowed for neuromorphic-sovereignty"
                     .to_string(),
@@ -111,37 +200,228 @@ impl MorpheusEngine {
         Ok(())
     }
 
-    pub fn register_example_endpoints(&self) {
+    pub fn register_example_endpoints(&self) -> Result<(), MorpheusError> {
         self.registry.register(
             "server1.morpheus-neuromorph.net",
             "https://api1.morpheus-neuromorph.net/v1/",
             "morpheus://key/server1",
             EndpointStatus::Active,
-        );
+        )?;
         self.registry.register(
             "server2.morpheus-neuromorph.net",
             "https://api2.morpheus-neuromorph.net/v1/",
             "morpheus://key/server2",
             EndpointStatus::Active,
-        );
+        )?;
         self.registry.register(
             "server3.morpheus-neuromorph.net",
             "https://api3.morpheus-neuromorph.net/v1/",
             "morpheus://key/server3",
             EndpointStatus::Inactive,
-        );
+        )?;
+        Ok(())
     }
 
     pub fn export_active_endpoints_json(&self) -> serde_json::Value {
         self.registry.to_json()
     }
 
+    /// `compliance.verify()` as JSON: `{ "overall_ok", "checks": [{ "name", "passed", "detail" }] }`.
+    pub fn compliance_report_json(&self) -> serde_json::Value {
+        serde_json::json!(self.compliance.verify())
+    }
+
+    /// Sign `identity` together with a fresh nonce and the current time,
+    /// using the security profile's signature algorithm.
     pub fn sign_neuromorph_identity(
         &self,
         identity: &str,
-    ) -> Result<Vec<u8>, MorpheusError> {
-        let secret = generate_random_secret();
-        let signature = hmac_sign(&secret, identity.as_bytes())?;
-        Ok(signature)
+    ) -> Result<SignedIdentity, MorpheusError> {
+        let nonce: String = self.random_secret()[..16]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect();
+        let issued_at = Utc::now();
+        let payload = SignedIdentity::payload(identity, &nonce, issued_at);
+        let algorithm = self.security_profile.signature_algorithm;
+        Ok(SignedIdentity {
+            identity: identity.to_string(),
+            nonce,
+            issued_at,
+            algorithm,
+            signature: sign(algorithm, &self.signing_key, &payload)?,
+        })
+    }
+
+    /// Accept `signed` once: it must use the profile's algorithm, the
+    /// signature must match, it must fall inside the identity window, and its
+    /// nonce must not have been seen before.
+    pub fn verify_neuromorph_identity(&self, signed: &SignedIdentity) -> Result<(), MorpheusError> {
+        let algorithm = self.security_profile.signature_algorithm;
+        if signed.algorithm != algorithm {
+            return Err(MorpheusError::IdentityRejected(format!(
+                "signed with {:?} but this engine requires {:?}",
+                signed.algorithm, algorithm
+            )));
+        }
+        let payload = SignedIdentity::payload(&signed.identity, &signed.nonce, signed.issued_at);
+        let key = verifying_key(algorithm, &self.signing_key)?;
+        if !verify(algorithm, &key, &payload, &signed.signature)? {
+            return Err(MorpheusError::IdentityRejected(
+                "signature does not match".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        let mut cache = self.nonce_cache.lock();
+        let age = now - signed.issued_at;
+        if age > cache.max_age() {
+            return Err(MorpheusError::IdentityRejected(format!(
+                "signature issued {}s ago exceeds the {}s window",
+                age.num_seconds(),
+                cache.max_age().num_seconds()
+            )));
+        }
+        if age < -Duration::seconds(IDENTITY_CLOCK_SKEW_SECS) {
+            return Err(MorpheusError::IdentityRejected(
+                "signature issued in the future".to_string(),
+            ));
+        }
+        match cache.insert(&signed.nonce, signed.issued_at, now) {
+            Ok(()) => Ok(()),
+            Err(NonceRejection::Replayed) => Err(MorpheusError::IdentityRejected(format!(
+                "nonce {} already used",
+                signed.nonce
+            ))),
+            Err(NonceRejection::CacheFull) => Err(MorpheusError::IdentityRejected(
+                "too many live identities in the window; retry later".to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct MemorySink(Mutex<Vec<ReversalAttempt>>);
+
+    impl ReversalAuditSink for MemorySink {
+        fn record(&self, attempt: &ReversalAttempt) {
+            self.0.lock().push(attempt.clone());
+        }
+    }
+
+    #[test]
+    fn forbidden_reversals_reach_the_audit_sink() {
+        let sink = Arc::new(MemorySink::default());
+        let engine = MorpheusEngine::new()
+            .unwrap()
+            .with_audit_sink(sink.clone());
+
+        engine.enforce_no_reversal("upgrade").unwrap();
+        assert!(engine.enforce_no_reversal("rollback").is_err());
+        assert!(engine
+            .enforce_no_reversal_for("downgrade", Some("operator-7"))
+            .is_err());
+
+        let attempts = sink.0.lock();
+        assert_eq!(attempts.len(), 2);
+        assert_eq!(attempts[0].action, "rollback");
+        assert_eq!(attempts[0].identity, None);
+        assert_eq!(attempts[1].identity.as_deref(), Some("operator-7"));
+    }
+
+    #[test]
+    fn signed_identities_cannot_be_replayed_or_forged() {
+        let engine = MorpheusEngine::new().unwrap().with_signing_key(b"stable".to_vec());
+        let signed = engine.sign_neuromorph_identity("operator-7").unwrap();
+
+        engine.verify_neuromorph_identity(&signed).unwrap();
+        assert!(matches!(
+            engine.verify_neuromorph_identity(&signed),
+            Err(MorpheusError::IdentityRejected(_))
+        ));
+
+        let mut forged = engine.sign_neuromorph_identity("operator-7").unwrap();
+        forged.identity = "operator-8".to_string();
+        assert!(engine.verify_neuromorph_identity(&forged).is_err());
+
+        let mut stale = engine.sign_neuromorph_identity("operator-7").unwrap();
+        stale.issued_at = Utc::now() - Duration::seconds(DEFAULT_IDENTITY_MAX_AGE_SECS + 1);
+        stale.signature = morpheus_security::hmac_sign(
+            b"stable",
+            &SignedIdentity::payload(&stale.identity, &stale.nonce, stale.issued_at),
+        )
+        .unwrap();
+        assert!(engine.verify_neuromorph_identity(&stale).is_err());
+    }
+
+    #[test]
+    fn identities_record_and_enforce_the_profile_algorithm() {
+        use morpheus_security::SignatureAlgorithm;
+
+        let mut engine = MorpheusEngine::new().unwrap();
+        engine.security_profile.signature_algorithm = SignatureAlgorithm::Ed25519;
+        let signed = engine.sign_neuromorph_identity("operator-7").unwrap();
+        assert_eq!(signed.algorithm, SignatureAlgorithm::Ed25519);
+        assert_eq!(signed.signature.len(), 64);
+
+        let mut downgraded = signed.clone();
+        downgraded.algorithm = SignatureAlgorithm::HmacSha256;
+        assert!(matches!(
+            engine.verify_neuromorph_identity(&downgraded),
+            Err(MorpheusError::IdentityRejected(_))
+        ));
+        engine.verify_neuromorph_identity(&signed).unwrap();
+    }
+
+    #[test]
+    fn seeded_engines_sign_identically() {
+        let sign = |seed| {
+            MorpheusEngine::new()
+                .unwrap()
+                .with_rng_seed(seed)
+                .sign_neuromorph_identity("operator-7")
+                .unwrap()
+        };
+        let (a, b) = (sign(42), sign(42));
+        assert_eq!(a.nonce, b.nonce);
+        assert_eq!(
+            MorpheusEngine::new().unwrap().with_rng_seed(42).signing_key,
+            MorpheusEngine::new().unwrap().with_rng_seed(42).signing_key
+        );
+        assert_ne!(sign(43).nonce, a.nonce);
+    }
+
+    #[test]
+    fn compliance_report_json_has_stable_shape() {
+        let report = MorpheusEngine::new().unwrap().compliance_report_json();
+        assert_eq!(report["overall_ok"], true);
+        let checks = report["checks"].as_array().unwrap();
+        assert!(!checks.is_empty());
+        for check in checks {
+            assert!(check["name"].is_string());
+            assert!(check["passed"].is_boolean());
+            assert!(check["detail"].is_string());
+        }
+    }
+
+    #[test]
+    fn nonce_cache_stays_bounded() {
+        let now = Utc::now();
+        let mut cache = NonceCache::new(Duration::seconds(60), 2);
+        assert_eq!(cache.insert("a", now, now), Ok(()));
+        assert_eq!(cache.insert("a", now, now), Err(NonceRejection::Replayed));
+        assert_eq!(cache.insert("b", now, now), Ok(()));
+        // Full of live nonces: new identities are refused, and "a" stays remembered.
+        assert_eq!(cache.insert("c", now, now), Err(NonceRejection::CacheFull));
+        assert_eq!(cache.insert("a", now, now), Err(NonceRejection::Replayed));
+
+        // Once the window passes the slots free up again.
+        let later = now + Duration::seconds(61);
+        assert_eq!(cache.insert("c", later, later), Ok(()));
+        assert_eq!(cache.insert("a", later, later), Ok(()));
     }
 }
@@ -0,0 +1,31 @@
+use parking_lot::Mutex;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::Arc;
+use uuid::Uuid;
+
+/// Randomness for record ids and `SelectionStrategy::Random`: OS entropy by
+/// default, or a shared seeded `StdRng` so tests can pin outcomes.
+#[derive(Clone, Default)]
+pub(crate) struct Entropy(Option<Arc<Mutex<StdRng>>>);
+
+impl Entropy {
+    pub(crate) fn seeded(seed: u64) -> Self {
+        Self(Some(Arc::new(Mutex::new(StdRng::seed_from_u64(seed)))))
+    }
+
+    pub(crate) fn uuid(&self) -> Uuid {
+        match &self.0 {
+            Some(rng) => uuid::Builder::from_random_bytes(rng.lock().gen()).into_uuid(),
+            None => Uuid::new_v4(),
+        }
+    }
+
+    /// Uniform index in `0..len`; `len` must be non-zero.
+    pub(crate) fn index(&self, len: usize) -> usize {
+        match &self.0 {
+            Some(rng) => rng.lock().gen_range(0..len),
+            None => rand::thread_rng().gen_range(0..len),
+        }
+    }
+}
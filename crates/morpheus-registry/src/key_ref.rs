@@ -0,0 +1,106 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+const SCHEME: &str = "morpheus://";
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum KeyRefError {
+    #[error("key reference must start with {SCHEME}: {0}")]
+    MissingScheme(String),
+    #[error("key reference has no host: {0}")]
+    EmptyHost(String),
+    #[error("key reference has no key path: {0}")]
+    EmptyPath(String),
+    #[error("key reference contains an empty path segment: {0}")]
+    EmptySegment(String),
+    #[error("key reference contains invalid character {1:?}: {0}")]
+    InvalidChar(String, char),
+}
+
+/// A validated `morpheus://<host>/<path>` key reference, e.g.
+/// `morpheus://key/server1`. Serializes as the plain string.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct KeyRef(String);
+
+impl KeyRef {
+    pub fn parse(s: &str) -> Result<Self, KeyRefError> {
+        let rest = s
+            .strip_prefix(SCHEME)
+            .ok_or_else(|| KeyRefError::MissingScheme(s.to_string()))?;
+        if let Some(c) = rest
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '/')))
+        {
+            return Err(KeyRefError::InvalidChar(s.to_string(), c));
+        }
+        let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+        if host.is_empty() {
+            return Err(KeyRefError::EmptyHost(s.to_string()));
+        }
+        if path.is_empty() {
+            return Err(KeyRefError::EmptyPath(s.to_string()));
+        }
+        if path.split('/').any(str::is_empty) {
+            return Err(KeyRefError::EmptySegment(s.to_string()));
+        }
+        Ok(Self(s.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn host(&self) -> &str {
+        self.path_and_host().0
+    }
+
+    pub fn path(&self) -> &str {
+        self.path_and_host().1
+    }
+
+    fn path_and_host(&self) -> (&str, &str) {
+        // Invariant from `parse`: scheme present and a '/' after the host.
+        self.0[SCHEME.len()..]
+            .split_once('/')
+            .expect("validated key reference")
+    }
+}
+
+impl FromStr for KeyRef {
+    type Err = KeyRefError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<String> for KeyRef {
+    type Error = KeyRefError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Self::parse(&s)
+    }
+}
+
+impl TryFrom<&str> for KeyRef {
+    type Error = KeyRefError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl From<KeyRef> for String {
+    fn from(k: KeyRef) -> Self {
+        k.0
+    }
+}
+
+impl fmt::Display for KeyRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
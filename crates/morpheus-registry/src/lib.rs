@@ -2,10 +2,23 @@ use chrono::{DateTime, Utc};
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use tokio::sync::RwLock as AsyncRwLock;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+use entropy::Entropy;
+
+mod entropy;
+mod key_ref;
+#[cfg(any(test, feature = "mock"))]
+pub mod mock;
+mod store;
+
+pub use key_ref::{KeyRef, KeyRefError};
+pub use store::EndpointStore;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EndpointStatus {
     Active,
     Inactive,
@@ -16,41 +29,165 @@ pub struct EndpointRecord {
     pub id: Uuid,
     pub server: String,
     pub endpoint_url: String,
-    pub api_key_ref: String,
+    pub api_key_ref: KeyRef,
     pub status: EndpointStatus,
     pub created_at: DateTime<Utc>,
 }
 
+impl EndpointRecord {
+    fn new(
+        id: Uuid,
+        server: impl Into<String>,
+        endpoint_url: impl Into<String>,
+        api_key_ref: &str,
+        status: EndpointStatus,
+    ) -> Result<Self, KeyRefError> {
+        Ok(Self {
+            id,
+            server: server.into(),
+            endpoint_url: endpoint_url.into(),
+            api_key_ref: KeyRef::parse(api_key_ref)?,
+            status,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+/// Serialize records as `{ "endpoints": [...] }`, ordered by `created_at` then
+/// `id` so exports are stable across runs.
+fn endpoints_json(mut records: Vec<EndpointRecord>) -> serde_json::Value {
+    records.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+    serde_json::json!({ "endpoints": records })
+}
+
+/// Records whose key reference is exactly `key_ref`, in `created_at` then `id` order.
+fn matching_key_ref<'a>(
+    records: impl Iterator<Item = &'a EndpointRecord>,
+    key_ref: &str,
+) -> Vec<EndpointRecord> {
+    let mut found: Vec<_> = records
+        .filter(|r| r.api_key_ref.as_str() == key_ref)
+        .cloned()
+        .collect();
+    found.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+    found
+}
+
+/// Set the status of record `id`, returning false if there is no such record.
+fn set_record_status(
+    records: &mut HashMap<Uuid, EndpointRecord>,
+    id: Uuid,
+    status: EndpointStatus,
+) -> bool {
+    match records.get_mut(&id) {
+        Some(record) => {
+            record.status = status;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Point every record using `old_ref` at `new_ref`, returning how many changed.
+fn rekey_records<'a>(
+    records: impl Iterator<Item = &'a mut EndpointRecord>,
+    old_ref: &str,
+    new_ref: &KeyRef,
+) -> usize {
+    let mut changed = 0;
+    for record in records.filter(|r| r.api_key_ref.as_str() == old_ref) {
+        record.api_key_ref = new_ref.clone();
+        changed += 1;
+    }
+    changed
+}
+
+/// How [`EndpointRegistry::select`] picks among active endpoints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Always the oldest active endpoint.
+    First,
+    /// Rotate through active endpoints in `created_at` then `id` order.
+    RoundRobin,
+    /// Uniformly random; reproducible on a registry built `with_seed`.
+    Random,
+}
+
+/// Pick one of `active` per `strategy`. The round-robin cursor and entropy
+/// are passed in so every clone of a registry advances the same ones.
+fn select_from(
+    mut active: Vec<EndpointRecord>,
+    strategy: SelectionStrategy,
+    cursor: &AtomicUsize,
+    entropy: &Entropy,
+) -> Option<EndpointRecord> {
+    if active.is_empty() {
+        return None;
+    }
+    active.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+    let index = match strategy {
+        SelectionStrategy::First => 0,
+        SelectionStrategy::RoundRobin => cursor.fetch_add(1, Ordering::Relaxed) % active.len(),
+        SelectionStrategy::Random => entropy.index(active.len()),
+    };
+    Some(active.swap_remove(index))
+}
+
+/// Synchronous registry backed by `parking_lot::RwLock`.
+///
+/// Lock guards never escape these methods, so calling them from async code is
+/// safe; async consumers that need to await while registering or listing
+/// should use [`AsyncEndpointRegistry`] instead.
 #[derive(Clone)]
 pub struct EndpointRegistry {
     inner: Arc<RwLock<HashMap<Uuid, EndpointRecord>>>,
+    cursor: Arc<AtomicUsize>,
+    entropy: Entropy,
+}
+
+impl Default for EndpointRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl EndpointRegistry {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(RwLock::new(HashMap::new())),
+            cursor: Arc::new(AtomicUsize::new(0)),
+            entropy: Entropy::default(),
+        }
+    }
+
+    /// A registry whose record ids and `Random` selections come from
+    /// `StdRng::seed_from_u64(seed)`, for reproducible tests.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            entropy: Entropy::seeded(seed),
+            ..Self::new()
         }
     }
 
+    /// Register an endpoint; a malformed `api_key_ref` is rejected here
+    /// rather than when the key is later resolved.
     pub fn register(
         &self,
         server: impl Into<String>,
         endpoint_url: impl Into<String>,
-        api_key_ref: impl Into<String>,
+        api_key_ref: impl AsRef<str>,
         status: EndpointStatus,
-    ) -> Uuid {
-        let id = Uuid::new_v4();
-        let record = EndpointRecord {
-            id,
-            server: server.into(),
-            endpoint_url: endpoint_url.into(),
-            api_key_ref: api_key_ref.into(),
+    ) -> Result<Uuid, KeyRefError> {
+        let record = EndpointRecord::new(
+            self.entropy.uuid(),
+            server,
+            endpoint_url,
+            api_key_ref.as_ref(),
             status,
-            created_at: Utc::now(),
-        };
+        )?;
+        let id = record.id;
         self.inner.write().insert(id, record);
-        id
+        Ok(id)
     }
 
     pub fn list_active(&self) -> Vec<EndpointRecord> {
@@ -62,8 +199,324 @@ impl EndpointRegistry {
             .collect()
     }
 
+    /// Mark endpoint `id` active or inactive, e.g. on failover. Returns false
+    /// if `id` is not registered.
+    pub fn set_status(&self, id: Uuid, status: EndpointStatus) -> bool {
+        set_record_status(&mut self.inner.write(), id, status)
+    }
+
+    /// Pick an active endpoint, or `None` if there are none. Clones of this
+    /// registry share the round-robin cursor as well as the map.
+    pub fn select(&self, strategy: SelectionStrategy) -> Option<EndpointRecord> {
+        select_from(self.list_active(), strategy, &self.cursor, &self.entropy)
+    }
+
+    /// Every record, active or not, that references `key_ref`.
+    pub fn find_by_key_ref(&self, key_ref: &str) -> Vec<EndpointRecord> {
+        matching_key_ref(self.inner.read().values(), key_ref)
+    }
+
+    /// Swap `old_ref` for `new_ref` on every record at once, e.g. after a key
+    /// is rotated or compromised. `new_ref` is validated before anything
+    /// changes; returns the number of records updated.
+    pub fn rekey(&self, old_ref: &str, new_ref: &str) -> Result<usize, KeyRefError> {
+        let new_ref = KeyRef::parse(new_ref)?;
+        Ok(rekey_records(
+            self.inner.write().values_mut(),
+            old_ref,
+            &new_ref,
+        ))
+    }
+
     pub fn to_json(&self) -> serde_json::Value {
         let records: Vec<_> = self.inner.read().values().cloned().collect();
-        serde_json::json!({ "endpoints": records })
+        endpoints_json(records)
+    }
+}
+
+/// Async registry backed by `tokio::sync::RwLock`, with the same API surface
+/// as [`EndpointRegistry`]. Use it where a lock may be held across `.await`
+/// (health checks, event emission) so the runtime is never blocked.
+#[derive(Clone)]
+pub struct AsyncEndpointRegistry {
+    inner: Arc<AsyncRwLock<HashMap<Uuid, EndpointRecord>>>,
+    cursor: Arc<AtomicUsize>,
+    entropy: Entropy,
+}
+
+impl Default for AsyncEndpointRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncEndpointRegistry {
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(AsyncRwLock::new(HashMap::new())),
+            cursor: Arc::new(AtomicUsize::new(0)),
+            entropy: Entropy::default(),
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            entropy: Entropy::seeded(seed),
+            ..Self::new()
+        }
+    }
+
+    pub async fn register(
+        &self,
+        server: impl Into<String>,
+        endpoint_url: impl Into<String>,
+        api_key_ref: impl AsRef<str>,
+        status: EndpointStatus,
+    ) -> Result<Uuid, KeyRefError> {
+        let record = EndpointRecord::new(
+            self.entropy.uuid(),
+            server,
+            endpoint_url,
+            api_key_ref.as_ref(),
+            status,
+        )?;
+        let id = record.id;
+        self.inner.write().await.insert(id, record);
+        Ok(id)
+    }
+
+    pub async fn list_active(&self) -> Vec<EndpointRecord> {
+        self.inner
+            .read()
+            .await
+            .values()
+            .filter(|r| matches!(r.status, EndpointStatus::Active))
+            .cloned()
+            .collect()
+    }
+
+    pub async fn set_status(&self, id: Uuid, status: EndpointStatus) -> bool {
+        set_record_status(&mut *self.inner.write().await, id, status)
+    }
+
+    pub async fn select(&self, strategy: SelectionStrategy) -> Option<EndpointRecord> {
+        select_from(self.list_active().await, strategy, &self.cursor, &self.entropy)
+    }
+
+    pub async fn find_by_key_ref(&self, key_ref: &str) -> Vec<EndpointRecord> {
+        matching_key_ref(self.inner.read().await.values(), key_ref)
+    }
+
+    pub async fn rekey(&self, old_ref: &str, new_ref: &str) -> Result<usize, KeyRefError> {
+        let new_ref = KeyRef::parse(new_ref)?;
+        Ok(rekey_records(
+            self.inner.write().await.values_mut(),
+            old_ref,
+            &new_ref,
+        ))
+    }
+
+    pub async fn to_json(&self) -> serde_json::Value {
+        let records: Vec<_> = self.inner.read().await.values().cloned().collect();
+        endpoints_json(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn async_registry_lists_only_active() {
+        let registry = AsyncEndpointRegistry::new();
+        registry
+            .register("a", "https://a/", "morpheus://key/a", EndpointStatus::Active)
+            .await
+            .unwrap();
+        registry
+            .register("b", "https://b/", "morpheus://key/b", EndpointStatus::Inactive)
+            .await
+            .unwrap();
+        let active = registry.list_active().await;
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].server, "a");
+    }
+
+    #[test]
+    fn to_json_orders_by_created_at_then_id() {
+        let registry = EndpointRegistry::new();
+        let ids: Vec<Uuid> = (0..5)
+            .map(|i| {
+                registry.register(
+                    format!("s{i}"),
+                    "https://x/",
+                    "morpheus://key/x",
+                    EndpointStatus::Active,
+                )
+                .unwrap()
+            })
+            .collect();
+        let json = registry.to_json();
+        let endpoints = json["endpoints"].as_array().unwrap();
+
+        let mut expected: Vec<EndpointRecord> =
+            ids.iter().map(|id| registry.inner.read()[id].clone()).collect();
+        expected.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        let got: Vec<String> = endpoints
+            .iter()
+            .map(|e| e["id"].as_str().unwrap().to_string())
+            .collect();
+        let want: Vec<String> = expected.iter().map(|r| r.id.to_string()).collect();
+        assert_eq!(got, want);
+        assert_eq!(registry.to_json(), json);
+    }
+
+    #[test]
+    fn round_robin_cursor_is_shared_across_clones() {
+        let registry = EndpointRegistry::new();
+        for name in ["a", "b", "c"] {
+            registry
+                .register(name, "https://x/", "morpheus://key/x", EndpointStatus::Active)
+                .unwrap();
+        }
+        let clone = registry.clone();
+        let picks: Vec<String> = (0..6)
+            .map(|i| {
+                let r = if i % 2 == 0 { &registry } else { &clone };
+                r.select(SelectionStrategy::RoundRobin).unwrap().id.to_string()
+            })
+            .collect();
+
+        let mut order = registry.list_active();
+        order.sort_by(|a, b| a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)));
+        let want: Vec<String> = order.iter().cycle().take(6).map(|r| r.id.to_string()).collect();
+        assert_eq!(picks, want);
+        assert_eq!(
+            clone.select(SelectionStrategy::First).unwrap().id,
+            order[0].id
+        );
+    }
+
+    #[test]
+    fn rekey_updates_every_matching_record() {
+        let registry = EndpointRegistry::new();
+        for (name, key, status) in [
+            ("a", "morpheus://key/server1", EndpointStatus::Active),
+            ("b", "morpheus://key/server1", EndpointStatus::Inactive),
+            ("c", "morpheus://key/server2", EndpointStatus::Active),
+        ] {
+            registry.register(name, "https://x/", key, status).unwrap();
+        }
+        assert_eq!(registry.find_by_key_ref("morpheus://key/server1").len(), 2);
+
+        assert!(registry
+            .rekey("morpheus://key/server1", "morpheus://key/ rotated")
+            .is_err());
+        assert_eq!(registry.find_by_key_ref("morpheus://key/server1").len(), 2);
+
+        let changed = registry
+            .rekey("morpheus://key/server1", "morpheus://key/server1-r2")
+            .unwrap();
+        assert_eq!(changed, 2);
+        assert!(registry.find_by_key_ref("morpheus://key/server1").is_empty());
+        let servers: Vec<String> = registry
+            .find_by_key_ref("morpheus://key/server1-r2")
+            .into_iter()
+            .map(|r| r.server)
+            .collect();
+        assert_eq!(servers.len(), 2);
+        assert!(servers.contains(&"a".to_string()) && servers.contains(&"b".to_string()));
+        assert_eq!(registry.find_by_key_ref("morpheus://key/server2").len(), 1);
+        assert_eq!(registry.rekey("morpheus://key/missing", "morpheus://key/x"), Ok(0));
+    }
+
+    /// Failover as a consumer would write it: against the trait only.
+    fn fail_over(store: &impl EndpointStore) -> Option<EndpointRecord> {
+        let current = store.select(SelectionStrategy::First)?;
+        store.set_status(current.id, EndpointStatus::Inactive);
+        store.select(SelectionStrategy::First)
+    }
+
+    #[test]
+    fn failover_works_against_mock_and_real_store() {
+        let mock = mock::MockEndpointStore::new();
+        for name in ["a", "b", "c"] {
+            mock.register(name, "https://x/", "morpheus://key/x", EndpointStatus::Active)
+                .unwrap();
+        }
+        let next = fail_over(&mock).unwrap();
+        assert_eq!((next.id, next.server.as_str()), (Uuid::from_u128(2), "b"));
+        assert_eq!(mock.list_active().len(), 2);
+        assert!(!mock.set_status(Uuid::from_u128(9), EndpointStatus::Active));
+
+        let registry = EndpointRegistry::new();
+        let only = EndpointStore::register(
+            &registry,
+            "a",
+            "https://x/",
+            "morpheus://key/x",
+            EndpointStatus::Active,
+        )
+        .unwrap();
+        assert!(fail_over(&registry).is_none());
+        assert!(registry.set_status(only, EndpointStatus::Active));
+        assert_eq!(registry.select(SelectionStrategy::First).unwrap().id, only);
+    }
+
+    #[test]
+    fn seeded_registries_repeat_ids_and_random_picks() {
+        let run = |seed| {
+            let registry = EndpointRegistry::with_seed(seed);
+            let ids: Vec<Uuid> = ["a", "b", "c", "d"]
+                .into_iter()
+                .map(|name| {
+                    registry
+                        .register(name, "https://x/", "morpheus://key/x", EndpointStatus::Active)
+                        .unwrap()
+                })
+                .collect();
+            let picks: Vec<Uuid> = (0..8)
+                .map(|_| registry.select(SelectionStrategy::Random).unwrap().id)
+                .collect();
+            (ids, picks)
+        };
+        let (ids, picks) = run(7);
+        assert_eq!(run(7), (ids.clone(), picks.clone()));
+        assert_ne!(run(8).0, ids);
+        assert!(picks.iter().all(|id| ids.contains(id)));
+    }
+
+    #[test]
+    fn register_rejects_malformed_key_refs() {
+        let registry = EndpointRegistry::new();
+        for bad in [
+            "key/server1",
+            "https://key/server1",
+            "morpheus://",
+            "morpheus:///server1",
+            "morpheus://key",
+            "morpheus://key/",
+            "morpheus://key//server1",
+            "morpheus://key/server 1",
+        ] {
+            assert!(
+                registry
+                    .register("s", "https://s/", bad, EndpointStatus::Active)
+                    .is_err(),
+                "{bad}"
+            );
+        }
+        assert!(registry.list_active().is_empty());
+
+        let id = registry
+            .register("s", "https://s/", "morpheus://key/server1", EndpointStatus::Active)
+            .unwrap();
+        let record = registry.inner.read()[&id].clone();
+        assert_eq!(record.api_key_ref.host(), "key");
+        assert_eq!(record.api_key_ref.path(), "server1");
+        assert_eq!(
+            registry.to_json()["endpoints"][0]["api_key_ref"],
+            "morpheus://key/server1"
+        );
     }
 }
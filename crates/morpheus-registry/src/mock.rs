@@ -0,0 +1,97 @@
+//! In-memory [`EndpointStore`] for tests, enabled by the `mock` feature.
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::sync::atomic::AtomicUsize;
+use uuid::Uuid;
+
+use crate::entropy::Entropy;
+use crate::{
+    select_from, set_record_status, EndpointRecord, EndpointStatus, EndpointStore, KeyRef,
+    KeyRefError, SelectionStrategy,
+};
+
+/// Deterministic [`EndpointStore`]: the n-th registration gets id
+/// `Uuid::from_u128(n)` and is created n seconds after the Unix epoch, so
+/// selection order is registration order and ids are stable across runs.
+/// `Random` selection uses a fixed seed (see `with_seed`).
+pub struct MockEndpointStore {
+    records: Mutex<HashMap<Uuid, EndpointRecord>>,
+    cursor: AtomicUsize,
+    entropy: Entropy,
+}
+
+impl Default for MockEndpointStore {
+    fn default() -> Self {
+        Self::with_seed(0)
+    }
+}
+
+impl MockEndpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seed the RNG behind `SelectionStrategy::Random`.
+    pub fn with_seed(seed: u64) -> Self {
+        Self {
+            records: Mutex::default(),
+            cursor: AtomicUsize::new(0),
+            entropy: Entropy::seeded(seed),
+        }
+    }
+
+    /// Every record, active or not, in registration order.
+    pub fn records(&self) -> Vec<EndpointRecord> {
+        let mut records: Vec<_> = self.records.lock().values().cloned().collect();
+        records.sort_by_key(|r| r.id);
+        records
+    }
+}
+
+fn mock_created_at(n: u128) -> DateTime<Utc> {
+    Utc.timestamp_opt(0, 0).unwrap() + Duration::seconds(n as i64)
+}
+
+impl EndpointStore for MockEndpointStore {
+    fn register(
+        &self,
+        server: &str,
+        endpoint_url: &str,
+        api_key_ref: &str,
+        status: EndpointStatus,
+    ) -> Result<Uuid, KeyRefError> {
+        let api_key_ref = KeyRef::parse(api_key_ref)?;
+        let mut records = self.records.lock();
+        let n = records.len() as u128 + 1;
+        let id = Uuid::from_u128(n);
+        records.insert(
+            id,
+            EndpointRecord {
+                id,
+                server: server.to_string(),
+                endpoint_url: endpoint_url.to_string(),
+                api_key_ref,
+                status,
+                created_at: mock_created_at(n),
+            },
+        );
+        Ok(id)
+    }
+
+    fn list_active(&self) -> Vec<EndpointRecord> {
+        self.records()
+            .into_iter()
+            .filter(|r| r.status == EndpointStatus::Active)
+            .collect()
+    }
+
+    fn set_status(&self, id: Uuid, status: EndpointStatus) -> bool {
+        set_record_status(&mut self.records.lock(), id, status)
+    }
+
+    fn select(&self, strategy: SelectionStrategy) -> Option<EndpointRecord> {
+        select_from(self.list_active(), strategy, &self.cursor, &self.entropy)
+    }
+}
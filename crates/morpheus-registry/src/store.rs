@@ -0,0 +1,51 @@
+use uuid::Uuid;
+
+use crate::{EndpointRecord, EndpointRegistry, EndpointStatus, KeyRefError, SelectionStrategy};
+
+/// The registry operations higher layers depend on.
+///
+/// Implemented by [`EndpointRegistry`]; with the `mock` feature,
+/// [`crate::mock::MockEndpointStore`] provides a deterministic stand-in so
+/// selection and failover logic can be unit tested in isolation.
+pub trait EndpointStore {
+    /// Register an endpoint, rejecting a malformed `api_key_ref`.
+    fn register(
+        &self,
+        server: &str,
+        endpoint_url: &str,
+        api_key_ref: &str,
+        status: EndpointStatus,
+    ) -> Result<Uuid, KeyRefError>;
+
+    fn list_active(&self) -> Vec<EndpointRecord>;
+
+    /// Returns false if `id` is not registered.
+    fn set_status(&self, id: Uuid, status: EndpointStatus) -> bool;
+
+    /// Pick an active endpoint, or `None` if there are none.
+    fn select(&self, strategy: SelectionStrategy) -> Option<EndpointRecord>;
+}
+
+impl EndpointStore for EndpointRegistry {
+    fn register(
+        &self,
+        server: &str,
+        endpoint_url: &str,
+        api_key_ref: &str,
+        status: EndpointStatus,
+    ) -> Result<Uuid, KeyRefError> {
+        EndpointRegistry::register(self, server, endpoint_url, api_key_ref, status)
+    }
+
+    fn list_active(&self) -> Vec<EndpointRecord> {
+        EndpointRegistry::list_active(self)
+    }
+
+    fn set_status(&self, id: Uuid, status: EndpointStatus) -> bool {
+        EndpointRegistry::set_status(self, id, status)
+    }
+
+    fn select(&self, strategy: SelectionStrategy) -> Option<EndpointRecord> {
+        EndpointRegistry::select(self, strategy)
+    }
+}
@@ -1,10 +1,12 @@
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
 use hmac::{Hmac, Mac};
 use rand::RngCore;
 use serde::{Deserialize, Serialize};
-use sha2::Sha256;
+use sha2::{Sha256, Sha512};
 use thiserror::Error;
 
 type HmacSha256 = Hmac<Sha256>;
+type HmacSha512 = Hmac<Sha512>;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SecurityPrimitive {
@@ -13,11 +15,24 @@ pub enum SecurityPrimitive {
     GoogolswarmOwnershipProof,
 }
 
+/// Algorithm used by [`sign`] and [`verify`]. Recorded next to each
+/// signature so it can be verified unambiguously after a migration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum SignatureAlgorithm {
+    #[default]
+    HmacSha256,
+    HmacSha512,
+    /// Signing key is a 32-byte seed; verifiers hold only the public key.
+    Ed25519,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SecurityProfile {
     pub primitives: Vec<SecurityPrimitive>,
     pub neuromorph_identity_required: bool,
     pub dna_based_mfa_placeholder: bool,
+    #[serde(default)]
+    pub signature_algorithm: SignatureAlgorithm,
 }
 
 #[derive(Debug, Error)]
@@ -26,6 +41,8 @@ pub enum SecurityError {
     InvalidProfile,
     #[error("hmac error")]
     HmacError,
+    #[error("invalid key for {0:?}")]
+    InvalidKey(SignatureAlgorithm),
 }
 
 impl SecurityProfile {
@@ -38,6 +55,7 @@ impl SecurityProfile {
             ],
             neuromorph_identity_required: true,
             dna_based_mfa_placeholder: false,
+            signature_algorithm: SignatureAlgorithm::default(),
         }
     }
 
@@ -50,8 +68,14 @@ impl SecurityProfile {
 }
 
 pub fn generate_random_secret() -> [u8; 32] {
+    generate_random_secret_with(&mut rand::thread_rng())
+}
+
+/// `generate_random_secret` drawing from `rng`, e.g. a seeded `StdRng` in
+/// tests. Production callers should keep the OS-seeded default.
+pub fn generate_random_secret_with(rng: &mut impl RngCore) -> [u8; 32] {
     let mut buf = [0u8; 32];
-    rand::thread_rng().fill_bytes(&mut buf);
+    rng.fill_bytes(&mut buf);
     buf
 }
 
@@ -61,3 +85,112 @@ pub fn hmac_sign(secret: &[u8], message: &[u8]) -> Result<Vec<u8>, SecurityError
     mac.update(message);
     Ok(mac.finalize().into_bytes().to_vec())
 }
+
+/// Constant-time check that `tag` is the HMAC-SHA256 of `message` under `secret`.
+pub fn hmac_verify(secret: &[u8], message: &[u8], tag: &[u8]) -> Result<bool, SecurityError> {
+    let mut mac =
+        HmacSha256::new_from_slice(secret).map_err(|_| SecurityError::HmacError)?;
+    mac.update(message);
+    Ok(mac.verify_slice(tag).is_ok())
+}
+
+fn ed25519_signing_key(secret: &[u8]) -> Result<SigningKey, SecurityError> {
+    let seed: [u8; 32] = secret
+        .try_into()
+        .map_err(|_| SecurityError::InvalidKey(SignatureAlgorithm::Ed25519))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+/// Sign `message` with `secret` under `algorithm`.
+pub fn sign(
+    algorithm: SignatureAlgorithm,
+    secret: &[u8],
+    message: &[u8],
+) -> Result<Vec<u8>, SecurityError> {
+    match algorithm {
+        SignatureAlgorithm::HmacSha256 => hmac_sign(secret, message),
+        SignatureAlgorithm::HmacSha512 => {
+            let mut mac =
+                HmacSha512::new_from_slice(secret).map_err(|_| SecurityError::HmacError)?;
+            mac.update(message);
+            Ok(mac.finalize().into_bytes().to_vec())
+        }
+        SignatureAlgorithm::Ed25519 => Ok(ed25519_signing_key(secret)?
+            .sign(message)
+            .to_bytes()
+            .to_vec()),
+    }
+}
+
+/// The key a verifier needs for signatures made with `secret`: the secret
+/// itself for HMAC, the 32-byte public key for Ed25519.
+pub fn verifying_key(
+    algorithm: SignatureAlgorithm,
+    secret: &[u8],
+) -> Result<Vec<u8>, SecurityError> {
+    match algorithm {
+        SignatureAlgorithm::HmacSha256 | SignatureAlgorithm::HmacSha512 => Ok(secret.to_vec()),
+        SignatureAlgorithm::Ed25519 => Ok(ed25519_signing_key(secret)?
+            .verifying_key()
+            .to_bytes()
+            .to_vec()),
+    }
+}
+
+/// Check `signature` over `message` against `key` from [`verifying_key`].
+/// HMAC tags are compared in constant time.
+pub fn verify(
+    algorithm: SignatureAlgorithm,
+    key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, SecurityError> {
+    match algorithm {
+        SignatureAlgorithm::HmacSha256 => hmac_verify(key, message, signature),
+        SignatureAlgorithm::HmacSha512 => {
+            let mut mac =
+                HmacSha512::new_from_slice(key).map_err(|_| SecurityError::HmacError)?;
+            mac.update(message);
+            Ok(mac.verify_slice(signature).is_ok())
+        }
+        SignatureAlgorithm::Ed25519 => {
+            let key: [u8; 32] = key
+                .try_into()
+                .map_err(|_| SecurityError::InvalidKey(SignatureAlgorithm::Ed25519))?;
+            let key = VerifyingKey::from_bytes(&key)
+                .map_err(|_| SecurityError::InvalidKey(SignatureAlgorithm::Ed25519))?;
+            let Ok(signature) = Signature::from_slice(signature) else {
+                return Ok(false);
+            };
+            Ok(key.verify_strict(message, &signature).is_ok())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_algorithm_round_trips_and_rejects_tampering() {
+        let secret = generate_random_secret();
+        for algorithm in [
+            SignatureAlgorithm::HmacSha256,
+            SignatureAlgorithm::HmacSha512,
+            SignatureAlgorithm::Ed25519,
+        ] {
+            let signature = sign(algorithm, &secret, b"payload").unwrap();
+            let key = verifying_key(algorithm, &secret).unwrap();
+            assert!(verify(algorithm, &key, b"payload", &signature).unwrap());
+            assert!(!verify(algorithm, &key, b"tampered", &signature).unwrap());
+        }
+        assert_eq!(
+            sign(SignatureAlgorithm::HmacSha256, &secret, b"payload").unwrap(),
+            hmac_sign(&secret, b"payload").unwrap()
+        );
+        assert!(matches!(
+            sign(SignatureAlgorithm::Ed25519, b"short", b"payload"),
+            Err(SecurityError::InvalidKey(SignatureAlgorithm::Ed25519))
+        ));
+    }
+}
@@ -2,9 +2,9 @@ mod model;
 mod scheduler;
 
 use anyhow::Result;
-use ceim-kernel::TimeSample;
+use ceim-kernel::{IntegrationMethod, TimeSample};
 
-use model::{Basin, ScheduleOption};
+use model::{Basin, RankWeights, ScheduleOption};
 use scheduler::rank_schedules;
 
 fn main() -> Result<()> {
@@ -55,11 +55,24 @@ fn main() -> Result<()> {
         },
     ];
 
-    let ranked = rank_schedules(&basins, &options, &samples)?;
+    let ranked = rank_schedules(
+        &basins,
+        &options,
+        &samples,
+        IntegrationMethod::default(),
+        &RankWeights::default(),
+    )?;
     for r in ranked {
+        if r.needs_review {
+            println!(
+                "{} {}-{} no regulatory limit on record; review manually",
+                r.basin_id, r.start_hour, r.end_hour
+            );
+            continue;
+        }
         println!(
-            "{} {}-{} K_n/kWh={:.3} K_n/ha={:.3}",
-            r.basin_id, r.start_hour, r.end_hour, r.k_n_per_kwh, r.k_n_per_hectare
+            "{} {}-{} K_n/kWh={:.3} K_n/ha={:.3} score={:.3}",
+            r.basin_id, r.start_hour, r.end_hour, r.k_n_per_kwh, r.k_n_per_hectare, r.composite_score
         );
     }
     Ok(())
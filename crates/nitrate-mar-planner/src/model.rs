@@ -21,4 +21,33 @@ pub struct RankedSchedule {
     pub end_hour: u32,
     pub k_n_per_kwh: f64,
     pub k_n_per_hectare: f64,
+    /// Weighted combination of the two ratios; schedules are ranked by this.
+    pub composite_score: f64,
+    /// No regulatory limit was on record, so the scores above are 0.0 for
+    /// lack of a yardstick. Such schedules are listed after the ranked ones.
+    #[serde(default)]
+    pub needs_review: bool,
+}
+
+/// Relative weights for combining the per-kWh and per-hectare ratios.
+/// The default (1, 0) ranks on per-kWh alone.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct RankWeights {
+    pub per_kwh: f64,
+    pub per_hectare: f64,
+}
+
+impl Default for RankWeights {
+    fn default() -> Self {
+        Self {
+            per_kwh: 1.0,
+            per_hectare: 0.0,
+        }
+    }
+}
+
+impl RankWeights {
+    pub fn composite(&self, k_n_per_kwh: f64, k_n_per_hectare: f64) -> f64 {
+        self.per_kwh * k_n_per_kwh + self.per_hectare * k_n_per_hectare
+    }
 }
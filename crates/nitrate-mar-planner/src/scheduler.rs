@@ -1,26 +1,31 @@
 use anyhow::Result;
 
-use ceim-kernel::{CeimKernel, RegulatoryLimits, TimeSample};
+use ceim-kernel::{
+    default_omega, CeimKernel, IntegrationMethod, OrderedScore, RegulatoryLimits, TimeSample,
+};
 
-use crate::model::{Basin, RankedSchedule, ScheduleOption};
+use crate::model::{Basin, RankWeights, RankedSchedule, ScheduleOption};
 
 pub fn rank_schedules(
     basins: &[Basin],
     options: &[ScheduleOption],
     samples: &[TimeSample],
+    method: IntegrationMethod,
+    weights: &RankWeights,
 ) -> Result<Vec<RankedSchedule>> {
     let mut results = Vec::new();
     for opt in options {
         if let Some(basin) = basins.iter().find(|b| b.id == opt.basin_id) {
-            let impact = CeimKernel::compute(
+            let impact = CeimKernel::compute_with(
                 "nitrate",
-                1.0,
+                default_omega("nitrate"),
                 samples,
                 &RegulatoryLimits {
                     epa: Some(1.0),
                     eu: Some(1.0),
                     who: Some(1.0),
                 },
+                method,
             );
             let k_n_per_kwh = impact.k_n / basin.energy_kwh_per_day.max(1.0);
             let k_n_per_hectare = impact.k_n / basin.area_ha.max(0.1);
@@ -30,9 +35,15 @@ pub fn rank_schedules(
                 end_hour: opt.end_hour,
                 k_n_per_kwh,
                 k_n_per_hectare,
+                composite_score: weights.composite(k_n_per_kwh, k_n_per_hectare),
+                needs_review: impact.needs_review(),
             });
         }
     }
-    results.sort_by(|a, b| b.k_n_per_kwh.partial_cmp(&a.k_n_per_kwh).unwrap());
+    results.sort_by(|a, b| {
+        a.needs_review
+            .cmp(&b.needs_review)
+            .then(OrderedScore(b.composite_score).cmp(&OrderedScore(a.composite_score)))
+    });
     Ok(results)
 }
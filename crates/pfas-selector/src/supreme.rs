@@ -1,5 +1,7 @@
+use std::cmp::Reverse;
+
 use anyhow::Result;
-use ceim-kernel::{CeimKernel, RegulatoryLimits, TimeSample};
+use ceim-kernel::{CeimKernel, OrderedScore, RegulatoryLimits, TimeSample};
 
 use crate::design::TreatmentDesign;
 
@@ -42,6 +44,6 @@ pub fn select_designs(
         out.push(score);
     }
 
-    out.sort_by(|a, b| b.k_n_per_kwh.partial_cmp(&a.k_n_per_kwh).unwrap());
+    out.sort_by_key(|s| Reverse(OrderedScore(s.k_n_per_kwh)));
     Ok(out)
 }
@@ -1,8 +1,47 @@
+use std::collections::HashMap;
+
 use serde::Deserialize;
 
+use crate::ecoimpact::EcoContext;
+use crate::families::FamilyConfig;
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct Config {
     pub poll_interval_seconds: u64,
     pub water_quality_feed_url: String,
+    /// Further feeds polled alongside `water_quality_feed_url` each tick.
+    #[serde(default)]
+    pub additional_feed_urls: Vec<String>,
     pub output_dir: String,
+    #[serde(default)]
+    pub eco_context: EcoContext,
+    /// Extra feed-name -> canonical contaminant aliases, on top of the defaults.
+    #[serde(default)]
+    pub contaminant_aliases: HashMap<String, String>,
+    /// Contaminant families rolled up into each shard's `families`.
+    #[serde(default)]
+    pub contaminant_families: FamilyConfig,
+    /// Ticks allowed in flight at once; a tick due while all slots are busy
+    /// is shed. The default of 1 means two ticks never overlap.
+    #[serde(default = "default_max_concurrent_ticks")]
+    pub max_concurrent_ticks: usize,
+    /// Decimal places kept for `k_n` and scores in written shards.
+    #[serde(default = "default_shard_decimals")]
+    pub shard_decimals: u32,
+}
+
+fn default_max_concurrent_ticks() -> usize {
+    1
+}
+
+fn default_shard_decimals() -> u32 {
+    crate::shards::DEFAULT_SHARD_DECIMALS
+}
+
+impl Config {
+    /// Every feed to poll, primary first.
+    pub fn feed_urls(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.water_quality_feed_url.as_str())
+            .chain(self.additional_feed_urls.iter().map(String::as_str))
+    }
 }
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::CeimNodeState;
+
+/// Scoring context for turning a node's K_n into a 0..1 eco-impact score.
+/// Written into each shard so readers can explain the scores.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EcoContext {
+    /// K_n at which the normalized load saturates to 1.0.
+    pub k_n_reference: f64,
+    /// Relative toxicity per contaminant (0..1), keyed by lowercase name.
+    pub toxicity_weights: HashMap<String, f64>,
+    /// Weight for contaminants missing from `toxicity_weights`.
+    pub default_toxicity: f64,
+}
+
+impl Default for EcoContext {
+    fn default() -> Self {
+        let toxicity_weights = [
+            ("arsenic", 1.0),
+            ("lead", 1.0),
+            ("pfas", 1.0),
+            ("nitrate", 0.6),
+            ("phosphate", 0.4),
+            ("tds", 0.2),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v))
+        .collect();
+        Self {
+            k_n_reference: 1.0,
+            toxicity_weights,
+            default_toxicity: 0.5,
+        }
+    }
+}
+
+impl EcoContext {
+    pub fn toxicity(&self, contaminant: &str) -> f64 {
+        self.toxicity_weights
+            .get(&contaminant.to_ascii_lowercase())
+            .copied()
+            .unwrap_or(self.default_toxicity)
+            .clamp(0.0, 1.0)
+    }
+}
+
+/// Normalized K_n (clamped to 0..1 against `k_n_reference`) scaled by toxicity.
+pub fn compute_ecoimpact(node: &CeimNodeState, context: &EcoContext) -> f64 {
+    if context.k_n_reference <= 0.0 || !node.k_n.is_finite() {
+        return 0.0;
+    }
+    let normalized = (node.k_n / context.k_n_reference).clamp(0.0, 1.0);
+    normalized * context.toxicity(&node.contaminant)
+}
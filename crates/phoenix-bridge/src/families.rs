@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use ceim-kernel::ImpactStatus;
+use serde::{Deserialize, Serialize};
+
+use crate::state::CeimNodeState;
+
+/// How member `k_n` values combine into a family `k_n`.
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum FamilyAggregation {
+    /// Additive, for group limits on the summed load (e.g. PFAS totals).
+    #[default]
+    Sum,
+    /// Worst single member.
+    Max,
+}
+
+/// Contaminant -> family grouping, e.g. PFOA and PFOS under "pfas".
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct FamilyConfig {
+    /// Family per canonical contaminant, keyed by lowercase name.
+    #[serde(default)]
+    pub families: HashMap<String, String>,
+    #[serde(default)]
+    pub aggregation: FamilyAggregation,
+}
+
+impl FamilyConfig {
+    pub fn family_of(&self, contaminant: &str) -> Option<&str> {
+        self.families
+            .get(&contaminant.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+}
+
+/// Aggregated impact of one contaminant family at one node.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct FamilyImpact {
+    pub node_id: String,
+    pub family: String,
+    pub k_n: f64,
+    /// Contributing contaminants, sorted.
+    pub members: Vec<String>,
+    /// A member had no limit on record; its K_n is left out of `k_n`.
+    pub needs_review: bool,
+}
+
+/// Roll nodes up into per-node family impacts, ordered by node then family.
+/// Contaminants without a configured family are left out.
+pub fn rollup_by_family(nodes: &[CeimNodeState], config: &FamilyConfig) -> Vec<FamilyImpact> {
+    let mut rollup: HashMap<(&str, &str), FamilyImpact> = HashMap::new();
+    for node in nodes {
+        let Some(family) = config.family_of(&node.contaminant) else {
+            continue;
+        };
+        let entry = rollup
+            .entry((node.node_id.as_str(), family))
+            .or_insert_with(|| FamilyImpact {
+                node_id: node.node_id.clone(),
+                family: family.to_string(),
+                k_n: 0.0,
+                members: Vec::new(),
+                needs_review: false,
+            });
+        entry.members.push(node.contaminant.clone());
+        if node.impact_status == ImpactStatus::NoLimitDefined {
+            entry.needs_review = true;
+            continue;
+        }
+        entry.k_n = match config.aggregation {
+            FamilyAggregation::Sum => entry.k_n + node.k_n,
+            FamilyAggregation::Max => entry.k_n.max(node.k_n),
+        };
+    }
+    let mut out: Vec<FamilyImpact> = rollup.into_values().collect();
+    for impact in &mut out {
+        impact.members.sort();
+    }
+    out.sort_by(|a, b| a.node_id.cmp(&b.node_id).then_with(|| a.family.cmp(&b.family)));
+    out
+}
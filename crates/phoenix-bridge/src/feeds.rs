@@ -10,6 +10,12 @@ pub struct WaterSample {
     pub c_in: f64,
     pub c_out: f64,
     pub flow_q: f64,
+    /// Instrument that produced the reading; many feeds omit it.
+    #[serde(default)]
+    pub sensor_id: Option<String>,
+    /// Sampling/lab method (e.g. "EPA 300.0"); many feeds omit it.
+    #[serde(default)]
+    pub method: Option<String>,
 }
 
 pub async fn fetch_samples(feed_url: &str) -> Result<Vec<WaterSample>> {
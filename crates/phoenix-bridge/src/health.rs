@@ -0,0 +1,64 @@
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+/// Consecutive fetch failures after which a feed is reported as failing.
+pub const FAILING_AFTER: u32 = 3;
+
+/// Ingestion health of one feed source.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct FeedHealth {
+    pub last_success: Option<DateTime<Utc>>,
+    pub consecutive_failures: u32,
+    /// Samples returned by the most recent successful fetch.
+    pub last_sample_count: usize,
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum FeedStatus {
+    Healthy,
+    /// Reachable, but the last fetch returned no samples.
+    Empty,
+    /// Failed recently, fewer than `FAILING_AFTER` times in a row.
+    Degraded,
+    Failing,
+}
+
+impl FeedHealth {
+    pub fn status(&self) -> FeedStatus {
+        if self.consecutive_failures >= FAILING_AFTER {
+            FeedStatus::Failing
+        } else if self.consecutive_failures > 0 {
+            FeedStatus::Degraded
+        } else if self.last_sample_count == 0 {
+            FeedStatus::Empty
+        } else {
+            FeedStatus::Healthy
+        }
+    }
+}
+
+/// Per-feed health, keyed by feed URL.
+#[derive(Debug, Default)]
+pub struct FeedHealthTracker {
+    feeds: BTreeMap<String, FeedHealth>,
+}
+
+impl FeedHealthTracker {
+    pub fn record_success(&mut self, feed: &str, sample_count: usize, at: DateTime<Utc>) {
+        let health = self.feeds.entry(feed.to_string()).or_default();
+        health.last_success = Some(at);
+        health.consecutive_failures = 0;
+        health.last_sample_count = sample_count;
+    }
+
+    pub fn record_failure(&mut self, feed: &str) {
+        self.feeds.entry(feed.to_string()).or_default().consecutive_failures += 1;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &FeedHealth)> {
+        self.feeds.iter().map(|(k, v)| (k.as_str(), v))
+    }
+}
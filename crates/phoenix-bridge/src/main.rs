@@ -1,21 +1,33 @@
 mod config;
+mod ecoimpact;
+mod families;
 mod feeds;
+mod health;
 mod state;
 mod shards;
+mod stats;
 
-use std::time::Duration;
+use std::future::Future;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
 use chrono::Utc;
-use tracing::{error, info};
+use tokio::sync::Semaphore;
+use tokio::time::MissedTickBehavior;
+use tracing::{error, info, warn};
 use tracing_subscriber::EnvFilter;
 
-use ceim-kernel::{CeimKernel, RegulatoryLimits, TimeSample};
+use ceim-kernel::{CeimKernel, ContaminantTaxonomy, OrderedScore, RegulatoryLimits, TimeSample};
 
 use config::Config;
+use ecoimpact::compute_ecoimpact;
+use families::rollup_by_family;
 use feeds::fetch_samples;
+use health::{FeedHealthTracker, FeedStatus};
 use shards::write_shard;
-use state::CeimNodeState;
+use state::{CeimNodeState, SampleProvenance};
+use stats::shard_stats;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -23,12 +35,74 @@ async fn main() -> Result<()> {
         .with_env_filter(EnvFilter::from_default_env())
         .init();
 
-    let cfg = load_config()?;
+    let cfg = Arc::new(load_config()?);
+    let shutdown = shutdown_signal()?;
+    tokio::pin!(shutdown);
+    let feed_health = Arc::new(Mutex::new(FeedHealthTracker::default()));
+    let poll_interval = Duration::from_secs(cfg.poll_interval_seconds);
+    let max_ticks = cfg.max_concurrent_ticks.max(1);
+    let tick_slots = Arc::new(Semaphore::new(max_ticks));
+    // Missed ticks are skipped rather than queued, so a slow tick sheds the
+    // ones it overlaps instead of triggering a catch-up burst afterwards.
+    let mut ticker = tokio::time::interval(poll_interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
     loop {
-        if let Err(e) = tick(&cfg).await {
-            error!("tick error: {e:?}");
+        tokio::select! {
+            _ = &mut shutdown => {
+                info!("shutdown signal received, waiting for in-flight ticks");
+                break;
+            }
+            _ = ticker.tick() => {}
         }
-        tokio::time::sleep(Duration::from_secs(cfg.poll_interval_seconds)).await;
+        let Ok(slot) = Arc::clone(&tick_slots).try_acquire_owned() else {
+            warn!(max_concurrent_ticks = max_ticks, "previous tick still running; shedding this one");
+            continue;
+        };
+        let cfg = Arc::clone(&cfg);
+        let feed_health = Arc::clone(&feed_health);
+        tokio::spawn(async move {
+            let _slot = slot;
+            let started = Instant::now();
+            if let Err(e) = tick(&cfg, &feed_health).await {
+                error!("tick error: {e:?}");
+            }
+            log_feed_health(&feed_health.lock().unwrap_or_else(PoisonError::into_inner));
+            let elapsed = started.elapsed();
+            if elapsed > poll_interval {
+                warn!(
+                    elapsed_ms = elapsed.as_millis() as u64,
+                    poll_interval_seconds = cfg.poll_interval_seconds,
+                    "tick overran the poll interval; skipping the ticks it overlapped"
+                );
+            }
+        });
+    }
+    // A tick always runs to completion (including its shard write).
+    let _all_idle = tick_slots.acquire_many(max_ticks as u32).await;
+    info!("exiting after final shard");
+    Ok(())
+}
+
+/// Resolves on SIGTERM or Ctrl-C. Handlers are installed up front, so a
+/// signal delivered mid-tick is held until the loop next checks for it.
+fn shutdown_signal() -> Result<impl Future<Output = ()>> {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut term = signal(SignalKind::terminate())?;
+        let mut interrupt = signal(SignalKind::interrupt())?;
+        Ok(async move {
+            tokio::select! {
+                _ = term.recv() => {}
+                _ = interrupt.recv() => {}
+            }
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        Ok(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
     }
 }
 
@@ -38,12 +112,38 @@ fn load_config() -> Result<Config> {
     Ok(cfg)
 }
 
-async fn tick(cfg: &Config) -> Result<()> {
+async fn tick(cfg: &Config, feed_health: &Mutex<FeedHealthTracker>) -> Result<()> {
     info!("fetching water samples");
-    let samples = fetch_samples(&cfg.water_quality_feed_url).await?;
+    // One failing feed must not starve the others; failures are tracked
+    // per feed instead of aborting the tick.
+    let mut samples = Vec::new();
+    let mut any_ok = false;
+    for url in cfg.feed_urls() {
+        let result = fetch_samples(url).await;
+        let mut health = feed_health.lock().unwrap_or_else(PoisonError::into_inner);
+        match result {
+            Ok(fetched) => {
+                health.record_success(url, fetched.len(), Utc::now());
+                any_ok = true;
+                samples.extend(fetched);
+            }
+            Err(e) => {
+                health.record_failure(url);
+                error!(feed = url, "feed fetch failed: {e:?}");
+            }
+        }
+    }
+    if !any_ok {
+        // Don't replace the last good shard with an empty one.
+        anyhow::bail!("all feeds failed; skipping shard write");
+    }
     let mut nodes = Vec::new();
 
-    let groups = group_by_node_and_contaminant(samples);
+    let mut taxonomy = ContaminantTaxonomy::default();
+    for (alias, canonical) in &cfg.contaminant_aliases {
+        taxonomy.add_alias(alias, canonical);
+    }
+    let groups = group_by_node_and_contaminant(samples, &taxonomy);
     for ((node_id, contaminant), s) in groups {
         let times: Vec<TimeSample> = s
             .iter()
@@ -62,27 +162,100 @@ async fn tick(cfg: &Config) -> Result<()> {
             who: Some(1.0),
         };
         let impact = CeimKernel::compute(&contaminant, 1.0, &times, &limits);
-        nodes.push(CeimNodeState {
+        let mut node = CeimNodeState {
             node_id: node_id.clone(),
             contaminant: contaminant.clone(),
             k_n: impact.k_n,
+            ecoimpact_score: 0.0,
+            impact_status: impact.impact_status,
             last_updated: Utc::now(),
-        });
+            provenance: peak_provenance(&s),
+        };
+        node.ecoimpact_score = compute_ecoimpact(&node, &cfg.eco_context);
+        if impact.needs_review() {
+            warn!(
+                node = %node_id,
+                contaminant = %contaminant,
+                "no regulatory limit on record; K_n not meaningful, flag for review"
+            );
+        }
+        nodes.push(node);
     }
 
-    write_shard(&cfg.output_dir, nodes)?;
+    let stats = shard_stats(&nodes);
+    let families = rollup_by_family(&nodes, &cfg.contaminant_families);
+    write_shard(
+        &cfg.output_dir,
+        nodes,
+        families,
+        &cfg.eco_context,
+        cfg.shard_decimals,
+    )?;
+    info!(
+        count = stats.count,
+        kn_p50 = stats.kn_p50,
+        kn_p90 = stats.kn_p90,
+        kn_max = stats.kn_max,
+        worst_node = stats.worst_node.as_deref().unwrap_or("-"),
+        "shard written"
+    );
     Ok(())
 }
 
+/// Provenance of the highest-outflow sample, if the feed reported any.
+fn peak_provenance(samples: &[WaterSample]) -> Option<SampleProvenance> {
+    samples
+        .iter()
+        .filter(|s| s.sensor_id.is_some() || s.method.is_some())
+        .max_by_key(|s| OrderedScore(s.c_out))
+        .map(|s| SampleProvenance {
+            sensor_id: s.sensor_id.clone(),
+            method: s.method.clone(),
+            sampled_at: s.timestamp,
+        })
+}
+
+/// One line per feed; failing feeds are raised to warn so they can be alerted on.
+fn log_feed_health(tracker: &FeedHealthTracker) {
+    for (feed, health) in tracker.iter() {
+        let status = health.status();
+        let last_success = health
+            .last_success
+            .map(|t| t.to_rfc3339())
+            .unwrap_or_else(|| "never".to_string());
+        if status == FeedStatus::Failing {
+            warn!(
+                feed,
+                ?status,
+                consecutive_failures = health.consecutive_failures,
+                last_success,
+                "feed health"
+            );
+        } else {
+            info!(
+                feed,
+                ?status,
+                consecutive_failures = health.consecutive_failures,
+                samples = health.last_sample_count,
+                last_success,
+                "feed health"
+            );
+        }
+    }
+}
+
 use std::collections::HashMap;
 use feeds::WaterSample;
 
+/// Groups by node and canonical contaminant, so aliases from different feeds
+/// ("NO3", "Nitrate-N") land in one series.
 fn group_by_node_and_contaminant(
     samples: Vec<WaterSample>,
+    taxonomy: &ContaminantTaxonomy,
 ) -> HashMap<(String, String), Vec<WaterSample>> {
     let mut map: HashMap<(String, String), Vec<WaterSample>> = HashMap::new();
     for s in samples {
-        map.entry((s.node_id.clone(), s.contaminant.clone()))
+        map.entry((s.node_id.clone(), taxonomy.canonicalize(&s.contaminant)))
             .or_default()
             .push(s);
     }
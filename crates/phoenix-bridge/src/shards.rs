@@ -2,26 +2,63 @@ use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Result;
+use ceim_kernel::round_to;
 use chrono::Utc;
 use serde::Serialize;
 
+use crate::ecoimpact::EcoContext;
+use crate::families::FamilyImpact;
 use crate::state::CeimNodeState;
 
+/// Bump whenever the on-disk shard layout changes; readers migrate older versions.
+pub const SHARD_SCHEMA_VERSION: u32 = 2;
+/// Decimal places kept for impact values unless configured otherwise.
+pub const DEFAULT_SHARD_DECIMALS: u32 = 6;
+
 #[derive(Serialize)]
 pub struct CeimShard {
+    pub schema_version: u32,
     pub generated_at: String,
     pub nodes: Vec<CeimNodeState>,
+    /// Per-node contaminant-family rollups (see `rollup_by_family`).
+    pub families: Vec<FamilyImpact>,
+    /// Scoring context every node's `ecoimpact_score` was computed with.
+    pub ecoimpact_weights: EcoContext,
 }
 
-pub fn write_shard(output_dir: &str, nodes: Vec<CeimNodeState>) -> Result<()> {
+/// Write `nodes`, their family rollups and the scoring context as a new
+/// shard, rounding impact values to `decimals` places so shard diffs stay
+/// readable.
+pub fn write_shard(
+    output_dir: &str,
+    mut nodes: Vec<CeimNodeState>,
+    mut families: Vec<FamilyImpact>,
+    eco_context: &EcoContext,
+    decimals: u32,
+) -> Result<()> {
+    for node in &mut nodes {
+        node.k_n = round_to(node.k_n, decimals);
+        node.ecoimpact_score = round_to(node.ecoimpact_score, decimals);
+    }
+    for family in &mut families {
+        family.k_n = round_to(family.k_n, decimals);
+    }
     let shard = CeimShard {
+        schema_version: SHARD_SCHEMA_VERSION,
         generated_at: Utc::now().to_rfc3339(),
         nodes,
+        families,
+        ecoimpact_weights: eco_context.clone(),
     };
     let json = serde_json::to_string_pretty(&shard)?;
     let mut path = PathBuf::from(output_dir);
     fs::create_dir_all(&path)?;
-    path.push(format!("ceim_shard_{}.json", shard.generated_at.replace(':', "_")));
-    fs::write(path, json)?;
+    let name = format!("ceim_shard_{}.json", shard.generated_at.replace(':', "_"));
+    // Write then rename so readers never see a half-written shard; the
+    // dot-prefixed temp name also sorts before real shards.
+    let tmp = path.join(format!(".{name}.tmp"));
+    path.push(name);
+    fs::write(&tmp, json)?;
+    fs::rename(&tmp, &path)?;
     Ok(())
 }
@@ -1,3 +1,4 @@
+use ceim-kernel::ImpactStatus;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -6,5 +7,21 @@ pub struct CeimNodeState {
     pub node_id: String,
     pub contaminant: String,
     pub k_n: f64,
+    /// Toxicity-weighted, normalized K_n in 0..1 (see `compute_ecoimpact`).
+    pub ecoimpact_score: f64,
+    /// `NoLimitDefined` nodes carry a meaningless 0.0 K_n and need review.
+    #[serde(default)]
+    pub impact_status: ImpactStatus,
     pub last_updated: DateTime<Utc>,
+    /// Source of the series' peak outflow reading, for tracing a breach back
+    /// to its instrument. `None` when the feed carries no provenance.
+    #[serde(default)]
+    pub provenance: Option<SampleProvenance>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SampleProvenance {
+    pub sensor_id: Option<String>,
+    pub method: Option<String>,
+    pub sampled_at: DateTime<Utc>,
 }
@@ -0,0 +1,97 @@
+use ceim-kernel::OrderedScore;
+use serde::Serialize;
+
+use crate::state::CeimNodeState;
+
+/// Distribution of `k_n` across the nodes of one shard.
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
+pub struct ShardStats {
+    pub count: usize,
+    pub kn_p50: f64,
+    pub kn_p90: f64,
+    pub kn_max: f64,
+    /// `node_id/contaminant` with the highest `k_n`; `None` for an empty shard.
+    pub worst_node: Option<String>,
+}
+
+/// Nearest-rank quantiles of `k_n`; an empty shard yields all zeros.
+pub fn shard_stats(nodes: &[CeimNodeState]) -> ShardStats {
+    let mut kn: Vec<f64> = nodes.iter().map(|n| n.k_n).collect();
+    if kn.is_empty() {
+        return ShardStats::default();
+    }
+    kn.sort_by_key(|v| OrderedScore(*v));
+    let worst = nodes
+        .iter()
+        .max_by_key(|n| OrderedScore(n.k_n))
+        .map(|n| format!("{}/{}", n.node_id, n.contaminant));
+    ShardStats {
+        count: kn.len(),
+        kn_p50: quantile(&kn, 0.5),
+        kn_p90: quantile(&kn, 0.9),
+        kn_max: kn[kn.len() - 1],
+        worst_node: worst,
+    }
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let rank = (q * sorted.len() as f64).ceil() as usize;
+    sorted[rank.clamp(1, sorted.len()) - 1]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn node(node_id: &str, k_n: f64) -> CeimNodeState {
+        CeimNodeState {
+            node_id: node_id.to_string(),
+            contaminant: "lead".to_string(),
+            k_n,
+            ecoimpact_score: 0.0,
+            impact_status: Default::default(),
+            last_updated: Utc::now(),
+            provenance: None,
+        }
+    }
+
+    #[test]
+    fn empty_shard_yields_zeros() {
+        assert_eq!(shard_stats(&[]), ShardStats::default());
+    }
+
+    #[test]
+    fn single_node_is_every_quantile() {
+        let stats = shard_stats(&[node("n1", 0.4)]);
+        assert_eq!(stats.count, 1);
+        assert_eq!((stats.kn_p50, stats.kn_p90, stats.kn_max), (0.4, 0.4, 0.4));
+        assert_eq!(stats.worst_node.as_deref(), Some("n1/lead"));
+    }
+
+    #[test]
+    fn quantiles_use_nearest_rank() {
+        // Shuffled 1..=10: p50 is the 5th value, p90 the 9th.
+        let nodes: Vec<_> = [7.0, 2.0, 10.0, 5.0, 1.0, 9.0, 3.0, 8.0, 4.0, 6.0]
+            .into_iter()
+            .enumerate()
+            .map(|(i, k_n)| node(&format!("n{}", i), k_n))
+            .collect();
+        let stats = shard_stats(&nodes);
+        assert_eq!(stats.count, 10);
+        assert_eq!((stats.kn_p50, stats.kn_p90, stats.kn_max), (5.0, 9.0, 10.0));
+        assert_eq!(stats.worst_node.as_deref(), Some("n2/lead"));
+    }
+
+    #[test]
+    fn nan_k_n_ranks_lowest() {
+        let stats = shard_stats(&[node("n1", f64::NAN), node("n2", 1.0), node("n3", 2.0)]);
+        assert_eq!(stats.count, 3);
+        assert_eq!((stats.kn_p50, stats.kn_p90, stats.kn_max), (1.0, 2.0, 2.0));
+        assert_eq!(stats.worst_node.as_deref(), Some("n3/lead"));
+
+        let all_nan = shard_stats(&[node("n1", f64::NAN)]);
+        assert!(all_nan.kn_p50.is_nan() && all_nan.kn_max.is_nan());
+        assert_eq!(all_nan.worst_node.as_deref(), Some("n1/lead"));
+    }
+}
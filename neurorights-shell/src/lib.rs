@@ -1,3 +1,6 @@
+use std::collections::VecDeque;
+use std::sync::{Mutex, PoisonError};
+
 use serde::{Deserialize, Serialize};
 
 /// Environment / embodiment plane for an interaction.
@@ -16,12 +19,33 @@ pub enum EnvironmentPlane {
 pub struct OuterActionRequest {
     pub plane: EnvironmentPlane,
     /// Purely outer metrics: emissions, energy, traffic deltas, etc.
+    /// Positive means added ecological load; zero or negative is neutral or an
+    /// improvement and is never bounded.
     pub eco_delta: f32,
+    /// Estimated physical risk of the action, 0..1.
     pub physical_risk: f32,
     /// Policy label (e.g., "traffic-signal-update", "hvac-adjust").
     pub policy_label: String,
 }
 
+/// Everything one request asks for, checked together by `evaluate_request`.
+/// Deliberately carries no inner-domain data, so it cannot feed gating.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FullRequest {
+    /// Outer action to authorize, if the request includes one.
+    #[serde(default)]
+    pub outer: Option<OuterActionRequest>,
+    /// Whether neural/biogenic data would leave the host.
+    #[serde(default)]
+    pub attempting_export: bool,
+    /// Whether the exported data could identify the host.
+    #[serde(default)]
+    pub is_reidentifiable: bool,
+    /// Augmentation the service requires, if any.
+    #[serde(default)]
+    pub required_augmentation: Option<String>,
+}
+
 /// Inner-domain hint is strictly host-local and never used for gating.
 /// It can be logged for therapy or safety, but not for permissions.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -41,6 +65,78 @@ pub enum NeurorightViolation {
     NeuralExportForbidden,
     /// Attempt to coerce augmentation for essential services.
     CoerciveUptake,
+    /// An outer-domain metric is over its policy ceiling (or NaN).
+    OuterLimitExceeded {
+        /// Field of `OuterActionRequest` that tripped the limit.
+        metric: String,
+        value: f32,
+        limit: f32,
+    },
+}
+
+/// Host-local record of one `authorize_outer_action` decision.
+/// Holds outer metrics only; inner-domain values are never recorded.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct DecisionRecord {
+    pub policy_label: String,
+    pub plane: EnvironmentPlane,
+    pub eco_delta: f32,
+    pub physical_risk: f32,
+    /// `None` if the action was authorized.
+    pub violation: Option<NeurorightViolation>,
+    /// Always false: gating has no access to inner-domain data.
+    pub inner_used_for_gating: bool,
+}
+
+/// Decisions taken from the host-local log by `drain_log`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DrainedLog {
+    /// Retained decisions, oldest first.
+    pub records: Vec<DecisionRecord>,
+    /// Decisions evicted since the previous drain because the log was full.
+    /// Non-zero means `records` does not cover every decision in that span.
+    pub dropped: u64,
+}
+
+#[derive(Debug, Default)]
+struct DecisionLog {
+    records: VecDeque<DecisionRecord>,
+    dropped: u64,
+}
+
+/// Host-local throttle advice derived from inner-domain hints.
+/// It carries no permission semantics and cannot be turned into an authorization.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ThrottleAdvisory {
+    /// No change to the current session.
+    Continue,
+    /// Slow down pacing / reduce stimulus intensity.
+    Ease,
+    /// Suggest a break before continuing.
+    Pause,
+}
+
+/// Reviewed exemption allowing a specific augmentation to be required
+/// (e.g. a cochlear implant for an essential communication service).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AugmentationExemption {
+    /// Augmentation this exemption covers (matched case-insensitively).
+    pub augmentation: String,
+    /// Clinical or legal justification recorded at review time.
+    pub justification: String,
+    /// Reviewer who approved the exemption; empty means not yet approved.
+    pub approver: String,
+}
+
+impl AugmentationExemption {
+    /// Approved only once both a justification and an approver are recorded.
+    pub fn is_approved(&self) -> bool {
+        !self.justification.trim().is_empty() && !self.approver.trim().is_empty()
+    }
+
+    fn covers(&self, augmentation: &str) -> bool {
+        self.is_approved() && self.augmentation.eq_ignore_ascii_case(augmentation.trim())
+    }
 }
 
 /// Policy profile describing how a system must behave.
@@ -49,6 +145,15 @@ pub struct NeurorightsPolicy {
     pub allow_neural_export: bool,
     pub allow_inner_for_safety_only: bool,
     pub essential_service: bool,
+    /// Reviewed exemptions from the no-coercive-uptake rule.
+    #[serde(default)]
+    pub exemptions: Vec<AugmentationExemption>,
+    /// Largest positive `eco_delta` an outer action may carry; `None` leaves it unbounded.
+    #[serde(default)]
+    pub max_eco_delta: Option<f32>,
+    /// Largest `physical_risk` an outer action may carry; `None` leaves it unbounded.
+    #[serde(default)]
+    pub max_physical_risk: Option<f32>,
 }
 
 impl Default for NeurorightsPolicy {
@@ -57,36 +162,142 @@ impl Default for NeurorightsPolicy {
             allow_neural_export: false,
             allow_inner_for_safety_only: true,
             essential_service: false,
+            exemptions: Vec::new(),
+            max_eco_delta: None,
+            max_physical_risk: None,
         }
     }
 }
 
+/// Default number of decisions kept in the host-local log before the oldest are dropped.
+pub const DEFAULT_DECISION_LOG_CAPACITY: usize = 1024;
+
 /// Core guard that enforces the neurorights constraints.
 /// It must be invoked before any access-control or scoring decision.
 pub struct NeurorightsShell {
     pub policy: NeurorightsPolicy,
+    /// In-memory trail of outer-action decisions, emptied by `drain_log`.
+    /// Holds at most `log_capacity` entries; past that the oldest are lost,
+    /// and the loss is counted in `DrainedLog::dropped` so gaps are visible.
+    decision_log: Mutex<DecisionLog>,
+    log_capacity: usize,
 }
 
 impl NeurorightsShell {
     pub fn new(policy: NeurorightsPolicy) -> Self {
-        Self { policy }
+        Self::with_log_capacity(policy, DEFAULT_DECISION_LOG_CAPACITY)
+    }
+
+    /// Like `new`, but keeps at most `log_capacity` decisions between drains.
+    /// A capacity of zero keeps no records but still counts every decision as dropped.
+    pub fn with_log_capacity(policy: NeurorightsPolicy, log_capacity: usize) -> Self {
+        Self {
+            policy,
+            decision_log: Mutex::new(DecisionLog::default()),
+            log_capacity,
+        }
+    }
+
+    /// Take every decision still held since the last drain, oldest first,
+    /// along with how many were dropped in that span.
+    pub fn drain_log(&self) -> DrainedLog {
+        let mut log = self.decision_log.lock().unwrap_or_else(PoisonError::into_inner);
+        let DecisionLog { records, dropped } = std::mem::take(&mut *log);
+        DrainedLog {
+            records: records.into(),
+            dropped,
+        }
     }
 
-    /// Validate that an outer action decision does NOT depend on inner-domain hints
-    /// for rights, access, or Karma. Inner hints may only be used for host-local
-    /// safety (e.g., throttling a session) and never for external permissions.
+    /// Authorize an outer action from outer-domain metrics only.
+    /// The signature takes no inner-domain data, so gating cannot depend on it.
+    /// Only positive `eco_delta` is bounded; an improvement always passes, and
+    /// NaN is rejected whenever a bound is set. Every decision is appended to
+    /// the host-local decision log, evicting (and counting) the oldest entry
+    /// once it is full.
     pub fn authorize_outer_action(
         &self,
-        _inner: Option<&InnerDomainHint>,
         outer: &OuterActionRequest,
     ) -> Result<(), NeurorightViolation> {
-        // This implementation intentionally ignores inner-domain hints entirely.
-        // All governance decisions must be driven by outer-domain metrics only.
-        let _ = outer; // suppress unused warning
+        let outcome = self.check_outer_limits(outer);
+        let record = DecisionRecord {
+            policy_label: outer.policy_label.clone(),
+            plane: outer.plane.clone(),
+            eco_delta: outer.eco_delta,
+            physical_risk: outer.physical_risk,
+            violation: outcome.clone().err(),
+            inner_used_for_gating: false,
+        };
+        let mut log = self.decision_log.lock().unwrap_or_else(PoisonError::into_inner);
+        if self.log_capacity == 0 {
+            log.dropped += 1;
+        } else {
+            if log.records.len() >= self.log_capacity {
+                log.records.pop_front();
+                log.dropped += 1;
+            }
+            log.records.push_back(record);
+        }
+        outcome
+    }
 
+    fn check_outer_limits(&self, outer: &OuterActionRequest) -> Result<(), NeurorightViolation> {
+        let limits = [
+            ("eco_delta", outer.eco_delta, self.policy.max_eco_delta.map(|m| m.max(0.0))),
+            ("physical_risk", outer.physical_risk, self.policy.max_physical_risk),
+        ];
+        for (metric, value, limit) in limits {
+            let Some(limit) = limit else { continue };
+            if value.is_nan() || value > limit {
+                return Err(NeurorightViolation::OuterLimitExceeded {
+                    metric: metric.to_string(),
+                    value,
+                    limit,
+                });
+            }
+        }
         Ok(())
     }
 
+    /// Run every applicable check on `req` and return all violations found,
+    /// in check order (outer action, export, augmentation). Empty means compliant.
+    pub fn evaluate_request(&self, req: &FullRequest) -> Vec<NeurorightViolation> {
+        let outer = req
+            .outer
+            .as_ref()
+            .map_or(Ok(()), |outer| self.authorize_outer_action(outer));
+        [
+            outer,
+            self.check_neural_export(req.attempting_export, req.is_reidentifiable),
+            self.check_augmentation_condition(req.required_augmentation.as_deref()),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+    }
+
+    /// Host-local throttle advisory from inner-domain hints.
+    /// Never receives an outer request and returns no permission decision;
+    /// when the policy disallows inner use even for safety, it always advises `Continue`.
+    pub fn throttle_advisory(&self, inner: &InnerDomainHint) -> ThrottleAdvisory {
+        if !self.policy.allow_inner_for_safety_only {
+            return ThrottleAdvisory::Continue;
+        }
+        let level = inner
+            .stress
+            .into_iter()
+            .chain(inner.cognitive_load)
+            .filter(|x| !x.is_nan())
+            .fold(0.0_f32, f32::max);
+        if level >= 0.8 {
+            ThrottleAdvisory::Pause
+        } else if level >= 0.5 {
+            ThrottleAdvisory::Ease
+        } else {
+            ThrottleAdvisory::Continue
+        }
+    }
+
     /// Enforce export rules for neural/biogenic data.
     pub fn check_neural_export(
         &self,
@@ -99,14 +310,229 @@ impl NeurorightsShell {
         Ok(())
     }
 
-    /// Enforce the "no coercive uptake" rule.
+    /// Enforce the "no coercive uptake" rule. `required_augmentation` names the
+    /// augmentation the service requires, if any; an essential service may only
+    /// require it under a matching approved exemption.
     pub fn check_augmentation_condition(
         &self,
-        requires_augmentation: bool,
+        required_augmentation: Option<&str>,
     ) -> Result<(), NeurorightViolation> {
-        if self.policy.essential_service && requires_augmentation {
+        let Some(augmentation) = required_augmentation else {
+            return Ok(());
+        };
+        if self.policy.essential_service
+            && !self.policy.exemptions.iter().any(|e| e.covers(augmentation))
+        {
             return Err(NeurorightViolation::CoerciveUptake);
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shell_with_bound(max: f32) -> NeurorightsShell {
+        NeurorightsShell::new(NeurorightsPolicy {
+            max_eco_delta: Some(max),
+            ..NeurorightsPolicy::default()
+        })
+    }
+
+    fn hvac(eco_delta: f32) -> OuterActionRequest {
+        OuterActionRequest {
+            plane: EnvironmentPlane::ExternalEnvironment,
+            eco_delta,
+            physical_risk: 0.0,
+            policy_label: "hvac-adjust".to_string(),
+        }
+    }
+
+    #[test]
+    fn decisions_are_logged_without_inner_data() {
+        let shell = shell_with_bound(0.1);
+        assert!(shell.authorize_outer_action(&hvac(-1.0)).is_ok());
+        assert!(shell.authorize_outer_action(&hvac(0.5)).is_err());
+
+        let log = shell.drain_log();
+        assert_eq!(log.dropped, 0);
+        let log = log.records;
+        assert_eq!(log.len(), 2);
+        assert!(log.iter().all(|r| r.policy_label == "hvac-adjust" && !r.inner_used_for_gating));
+        assert!(log[0].violation.is_none());
+        assert_eq!(log[1].eco_delta, 0.5);
+        assert!(log[1].violation.is_some());
+        assert!(shell.drain_log().records.is_empty());
+    }
+
+    #[test]
+    fn decision_log_keeps_only_the_newest_entries() {
+        let shell = NeurorightsShell::with_log_capacity(NeurorightsPolicy::default(), 3);
+        for eco_delta in 0..5 {
+            assert!(shell.authorize_outer_action(&hvac(eco_delta as f32)).is_ok());
+        }
+        let drained = shell.drain_log();
+        let kept: Vec<f32> = drained.records.iter().map(|r| r.eco_delta).collect();
+        assert_eq!(kept, [2.0, 3.0, 4.0]);
+        assert_eq!(drained.dropped, 2);
+        // The counter covers one drain window at a time.
+        assert!(shell.authorize_outer_action(&hvac(5.0)).is_ok());
+        assert_eq!(shell.drain_log().dropped, 0);
+
+        let disabled = NeurorightsShell::with_log_capacity(NeurorightsPolicy::default(), 0);
+        assert!(disabled.authorize_outer_action(&hvac(0.0)).is_ok());
+        let drained = disabled.drain_log();
+        assert!(drained.records.is_empty());
+        assert_eq!(drained.dropped, 1);
+    }
+
+    fn essential_with(exemptions: Vec<AugmentationExemption>) -> NeurorightsShell {
+        NeurorightsShell::new(NeurorightsPolicy {
+            essential_service: true,
+            exemptions,
+            ..NeurorightsPolicy::default()
+        })
+    }
+
+    fn exemption(augmentation: &str, justification: &str, approver: &str) -> AugmentationExemption {
+        AugmentationExemption {
+            augmentation: augmentation.to_string(),
+            justification: justification.to_string(),
+            approver: approver.to_string(),
+        }
+    }
+
+    #[test]
+    fn approved_matching_exemption_allows_required_augmentation() {
+        let shell = essential_with(vec![exemption(
+            "Cochlear-Implant",
+            "only channel for relay calls",
+            "ethics-board",
+        )]);
+        assert!(shell.check_augmentation_condition(Some(" cochlear-implant ")).is_ok());
+        assert!(shell.check_augmentation_condition(None).is_ok());
+    }
+
+    #[test]
+    fn unapproved_exemption_is_still_coercive() {
+        for unapproved in [
+            exemption("cochlear-implant", "only channel for relay calls", ""),
+            exemption("cochlear-implant", "  ", "ethics-board"),
+        ] {
+            let shell = essential_with(vec![unapproved]);
+            assert!(matches!(
+                shell.check_augmentation_condition(Some("cochlear-implant")),
+                Err(NeurorightViolation::CoerciveUptake)
+            ));
+        }
+    }
+
+    #[test]
+    fn exemption_covers_only_its_own_augmentation() {
+        let shell = essential_with(vec![exemption(
+            "cochlear-implant",
+            "only channel for relay calls",
+            "ethics-board",
+        )]);
+        assert!(matches!(
+            shell.check_augmentation_condition(Some("retinal-implant")),
+            Err(NeurorightViolation::CoerciveUptake)
+        ));
+    }
+
+    #[test]
+    fn non_essential_service_may_require_augmentation() {
+        let shell = NeurorightsShell::new(NeurorightsPolicy::default());
+        assert!(shell.check_augmentation_condition(Some("retinal-implant")).is_ok());
+    }
+
+    #[test]
+    fn evaluate_request_reports_every_violation_in_order() {
+        let shell = NeurorightsShell::new(NeurorightsPolicy {
+            essential_service: true,
+            max_eco_delta: Some(0.1),
+            ..NeurorightsPolicy::default()
+        });
+        let violations = shell.evaluate_request(&FullRequest {
+            outer: Some(hvac(0.5)),
+            attempting_export: true,
+            is_reidentifiable: true,
+            required_augmentation: Some("retinal-implant".to_string()),
+        });
+        assert_eq!(violations.len(), 3);
+        assert!(matches!(
+            &violations[0],
+            NeurorightViolation::OuterLimitExceeded { metric, .. } if metric == "eco_delta"
+        ));
+        assert!(matches!(violations[1], NeurorightViolation::NeuralExportForbidden));
+        assert!(matches!(violations[2], NeurorightViolation::CoerciveUptake));
+
+        let compliant = FullRequest {
+            outer: Some(hvac(0.05)),
+            ..FullRequest::default()
+        };
+        assert!(shell.evaluate_request(&compliant).is_empty());
+    }
+
+    #[test]
+    fn high_physical_risk_is_rejected() {
+        let shell = NeurorightsShell::new(NeurorightsPolicy {
+            max_physical_risk: Some(0.3),
+            ..NeurorightsPolicy::default()
+        });
+        let mut request = hvac(0.0);
+        request.physical_risk = 0.9;
+        match shell.authorize_outer_action(&request) {
+            Err(NeurorightViolation::OuterLimitExceeded { metric, value, limit }) => {
+                assert_eq!(metric, "physical_risk");
+                assert_eq!((value, limit), (0.9, 0.3));
+            }
+            other => panic!("expected OuterLimitExceeded, got {:?}", other),
+        }
+        request.physical_risk = 0.3;
+        assert!(shell.authorize_outer_action(&request).is_ok());
+    }
+
+    #[test]
+    fn inner_stress_never_affects_authorization() {
+        let shell = NeurorightsShell::new(NeurorightsPolicy {
+            max_physical_risk: Some(0.3),
+            ..NeurorightsPolicy::default()
+        });
+        let mut risky = hvac(0.0);
+        risky.physical_risk = 0.9;
+        for stress in [None, Some(0.0), Some(1.0)] {
+            let inner = InnerDomainHint {
+                stress,
+                cognitive_load: stress,
+            };
+            // The hint changes the host-local advisory but never the gate
+            let advisory = shell.throttle_advisory(&inner);
+            assert_eq!(advisory == ThrottleAdvisory::Pause, stress == Some(1.0));
+            assert!(shell.authorize_outer_action(&hvac(0.0)).is_ok());
+            assert!(shell.authorize_outer_action(&risky).is_err());
+        }
+    }
+
+    #[test]
+    fn negative_and_zero_eco_delta_always_pass() {
+        let shell = shell_with_bound(0.1);
+        assert!(shell.authorize_outer_action(&hvac(-50.0)).is_ok());
+        assert!(shell.authorize_outer_action(&hvac(0.0)).is_ok());
+        assert!(shell_with_bound(0.0).authorize_outer_action(&hvac(-0.5)).is_ok());
+    }
+
+    #[test]
+    fn excessive_positive_eco_delta_is_rejected() {
+        let shell = shell_with_bound(0.1);
+        assert!(shell.authorize_outer_action(&hvac(0.1)).is_ok());
+        assert!(matches!(
+            shell.authorize_outer_action(&hvac(0.2)),
+            Err(NeurorightViolation::OuterLimitExceeded { ref metric, .. }) if metric == "eco_delta"
+        ));
+        assert!(shell.authorize_outer_action(&hvac(f32::NAN)).is_err());
+        let unbounded = NeurorightsShell::new(NeurorightsPolicy::default());
+        assert!(unbounded.authorize_outer_action(&hvac(1e6)).is_ok());
+    }
+}